@@ -8,7 +8,7 @@ use codspeed_criterion_compat::{
 use dataz::col::{Col, Data};
 use dataz::{Set, Table, TableFnMut};
 
-fn table_iter_fn<T: Table>(mut table: T) -> impl FnMut() -> usize {
+fn table_iter_fn<T: Table>(table: T) -> impl FnMut() -> usize {
     let mut batch = <<T as Table>::Data as Data>::Col::default();
     move || {
         let mut good_bytes = 0;
@@ -68,10 +68,83 @@ fn gen(c: &mut Criterion) {
         dataz::tpcc::Tpcc::init(dataz::tpcc::TpccConfig {
             warehouses: 1,
             now: dataz::tpcc::TpccConfig::FEB_18_2023_1_PM,
+            c_load: 0,
+            warehouse_range: None,
+            warehouse_filter: None,
         }),
     );
 }
 
+/// Compares the per-table throughput of the row-by-row [csv] writer against
+/// [dataz::arrow::write_parquet]'s builder-backed columnar path.
+#[cfg(all(feature = "rand", feature = "arrow", feature = "serde"))]
+fn csv_vs_parquet(c: &mut Criterion) {
+    use dataz::arrow::ArrowData;
+    use dataz::tpcc::TpccConfig;
+
+    fn bench_table<T>(c: &mut Criterion, table: T)
+    where
+        T: Table + Clone,
+        T::Data: ArrowData,
+        for<'a> <<T as Table>::Data as Data>::Ref<'a>: serde::Serialize,
+    {
+        let mut csv_bytes = Vec::new();
+        let mut writer = csv::Writer::from_writer(&mut csv_bytes);
+        let mut batch = <T::Data as Data>::Col::default();
+        for idx in 0..table.num_batches() {
+            batch.clear();
+            table.gen_batch(idx, &mut batch);
+            for i in 0..batch.len() {
+                writer.serialize(&batch.get(i)).unwrap();
+            }
+        }
+        drop(writer);
+
+        let mut g = c.benchmark_group(format!("csv_vs_parquet/{}", table.name()));
+        g.throughput(Throughput::Bytes(csv_bytes.len() as u64));
+        g.bench_function(BenchmarkId::new(table.name(), "csv"), |b| {
+            b.iter(|| {
+                let mut buf = Vec::new();
+                let mut writer = csv::Writer::from_writer(&mut buf);
+                let mut batch = <T::Data as Data>::Col::default();
+                for idx in 0..table.num_batches() {
+                    batch.clear();
+                    table.gen_batch(idx, &mut batch);
+                    for i in 0..batch.len() {
+                        writer.serialize(&batch.get(i)).unwrap();
+                    }
+                }
+                drop(writer);
+                black_box(buf.len())
+            });
+        });
+        g.bench_function(BenchmarkId::new(table.name(), "parquet"), |b| {
+            b.iter(|| {
+                let mut buf = Vec::new();
+                dataz::arrow::write_parquet(table.clone(), &mut buf).unwrap();
+                black_box(buf.len())
+            });
+        });
+    }
+
+    let config = TpccConfig {
+        warehouses: 1,
+        now: TpccConfig::FEB_18_2023_1_PM,
+        c_load: 0,
+        warehouse_range: None,
+        warehouse_filter: None,
+    };
+    bench_table(c, dataz::tpcc::Item::init(config.clone()));
+    bench_table(c, dataz::tpcc::Warehouse::init(config.clone()));
+    bench_table(c, dataz::tpcc::Stock::init(config.clone()));
+    bench_table(c, dataz::tpcc::District::init(config.clone()));
+    bench_table(c, dataz::tpcc::Customer::init(config.clone()));
+    bench_table(c, dataz::tpcc::History::init(config.clone()));
+    bench_table(c, dataz::tpcc::Order::init(config.clone()));
+    bench_table(c, dataz::tpcc::OrderLine::init(config.clone()));
+    bench_table(c, dataz::tpcc::NewOrder::init(config));
+}
+
 pub fn human_bytes(x: usize) -> String {
     const KIB: usize = 1024;
     const MIB: usize = 1024 * KIB;
@@ -90,4 +163,12 @@ pub fn human_bytes(x: usize) -> String {
 // The grouping here is an artifact of criterion's interaction with the
 // plug-able rust benchmark harness. We use criterion's groups instead.
 criterion_group!(benches, gen);
+
+#[cfg(all(feature = "rand", feature = "arrow", feature = "serde"))]
+criterion_group!(io_benches, csv_vs_parquet);
+
+#[cfg(all(feature = "rand", feature = "arrow", feature = "serde"))]
+criterion_main!(benches, io_benches);
+
+#[cfg(not(all(feature = "rand", feature = "arrow", feature = "serde")))]
 criterion_main!(benches);
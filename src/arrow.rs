@@ -0,0 +1,590 @@
+// Copyright 2023 Daniel Harrison. All Rights Reserved.
+
+//! [Apache Arrow] / [Parquet] export built on the [Col]/[Data] traits.
+//!
+//! The columnar layout the [crate::col] module already stores is exactly what
+//! Arrow's buffers want: primitive columns map to primitive arrays, the
+//! `(Vec<bool>, C)` validity column maps to a null bitmap, and the
+//! variable-length `(Vec<usize>, _)` columns map to Arrow's offsets + values
+//! layout. The one wrinkle is offsets: this crate stores cumulative *end*
+//! offsets, while Arrow wants *start* offsets with a leading `0`.
+//!
+//! [Apache Arrow]: https://arrow.apache.org/
+//! [Parquet]: https://parquet.apache.org/
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayBuilder, ArrayRef, BinaryArray, BooleanBuilder, Float32Builder, Float64Builder,
+    Int16Builder, Int32Builder, Int64Builder, Int8Builder, StringArray, StringBuilder,
+    UInt16Builder, UInt32Builder, UInt64Builder, UInt8Builder,
+};
+use arrow::buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+
+use crate::col::{Col, Data};
+use crate::Table;
+
+/// A [Col] that can be materialized as one or more Arrow arrays.
+///
+/// Leaf columns contribute a single field; tuple columns contribute one field
+/// per element, mirroring the [Col] tuple impls in [crate::col]. Field names
+/// are generated positionally (`f0`, `f1`, ...) since the [Data] tuples carry
+/// no names.
+pub trait ColToArrow {
+    /// Appends this column's Arrow field(s) to `fields`.
+    ///
+    /// `idx` is the running field counter, advanced once per contributed field.
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>);
+
+    /// Appends this column's Arrow array(s) to `arrays`.
+    fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError>;
+}
+
+fn next_field(idx: &mut usize, data_type: DataType, nullable: bool) -> Field {
+    let field = Field::new(format!("f{idx}"), data_type, nullable);
+    *idx += 1;
+    field
+}
+
+macro_rules! col_to_arrow_primitive {
+    ( $data:ty, $arrow:ty, $dt:expr ) => {
+        impl ColToArrow for Vec<$data> {
+            fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+                fields.push(next_field(idx, $dt, false));
+            }
+
+            fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError> {
+                arrays.push(Arc::new(<$arrow>::from(self.clone())));
+                Ok(())
+            }
+        }
+    };
+}
+
+col_to_arrow_primitive!(bool, arrow::array::BooleanArray, DataType::Boolean);
+col_to_arrow_primitive!(u8, arrow::array::UInt8Array, DataType::UInt8);
+col_to_arrow_primitive!(u16, arrow::array::UInt16Array, DataType::UInt16);
+col_to_arrow_primitive!(u32, arrow::array::UInt32Array, DataType::UInt32);
+col_to_arrow_primitive!(u64, arrow::array::UInt64Array, DataType::UInt64);
+col_to_arrow_primitive!(i8, arrow::array::Int8Array, DataType::Int8);
+col_to_arrow_primitive!(i16, arrow::array::Int16Array, DataType::Int16);
+col_to_arrow_primitive!(i32, arrow::array::Int32Array, DataType::Int32);
+col_to_arrow_primitive!(i64, arrow::array::Int64Array, DataType::Int64);
+col_to_arrow_primitive!(f32, arrow::array::Float32Array, DataType::Float32);
+col_to_arrow_primitive!(f64, arrow::array::Float64Array, DataType::Float64);
+
+/// Converts this crate's cumulative end offsets into Arrow's start offsets with
+/// a leading `0`.
+fn offset_buffer(lens: &[usize]) -> OffsetBuffer<i32> {
+    let mut offsets = Vec::with_capacity(lens.len() + 1);
+    offsets.push(0i32);
+    offsets.extend(lens.iter().map(|&end| end as i32));
+    OffsetBuffer::new(ScalarBuffer::from(offsets))
+}
+
+impl ColToArrow for (Vec<usize>, String) {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+        fields.push(next_field(idx, DataType::Utf8, false));
+    }
+
+    fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError> {
+        let (lens, concat) = self;
+        let array = StringArray::new(offset_buffer(lens), Buffer::from(concat.as_bytes()), None);
+        arrays.push(Arc::new(array));
+        Ok(())
+    }
+}
+
+impl ColToArrow for (Vec<usize>, Vec<u8>) {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+        fields.push(next_field(idx, DataType::Binary, false));
+    }
+
+    fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError> {
+        let (lens, concat) = self;
+        let array = BinaryArray::new(offset_buffer(lens), Buffer::from(concat.as_slice()), None);
+        arrays.push(Arc::new(array));
+        Ok(())
+    }
+}
+
+impl<C: ColToArrow> ColToArrow for (Vec<bool>, C) {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+        let (_, values) = self;
+        let before = fields.len();
+        values.fields(idx, fields);
+        // The inner column contributes exactly one field; make it nullable.
+        for field in &mut fields[before..] {
+            *field = field.clone().with_nullable(true);
+        }
+    }
+
+    fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError> {
+        let (set, values) = self;
+        let before = arrays.len();
+        values.arrays(arrays)?;
+        // Graft the validity bitmap onto the single array the inner column
+        // produced.
+        for array in &mut arrays[before..] {
+            let nulls = NullBuffer::from(set.clone());
+            let data = array.to_data().into_builder().nulls(Some(nulls)).build()?;
+            *array = arrow::array::make_array(data);
+        }
+        Ok(())
+    }
+}
+
+macro_rules! col_to_arrow_tuple {
+    ( $( $col:ident )+ ) => {
+        #[allow(non_snake_case)]
+        impl<$($col: ColToArrow),+> ColToArrow for ($($col),+) {
+            fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+                let ($($col),+) = self;
+                $(
+                    $col.fields(idx, fields);
+                )+
+            }
+
+            fn arrays(&self, arrays: &mut Vec<ArrayRef>) -> Result<(), ArrowError> {
+                let ($($col),+) = self;
+                $(
+                    $col.arrays(arrays)?;
+                )+
+                Ok(())
+            }
+        }
+    };
+}
+
+col_to_arrow_tuple! { C0 C1 C2 }
+col_to_arrow_tuple! { C0 C1 C2 C3 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA }
+col_to_arrow_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA CB }
+
+/// The Arrow schema derived from a [Table]'s [Table::Data].
+pub fn schema<T>() -> SchemaRef
+where
+    T: Table,
+    <T::Data as Data>::Col: ColToArrow + Default,
+{
+    let col = <T::Data as Data>::Col::default();
+    let mut idx = 0;
+    let mut fields = Vec::new();
+    col.fields(&mut idx, &mut fields);
+    Arc::new(Schema::new(fields))
+}
+
+/// Converts a single generated batch column into an Arrow [RecordBatch].
+pub fn record_batch<C>(col: &C, schema: SchemaRef) -> Result<RecordBatch, ArrowError>
+where
+    C: ColToArrow,
+{
+    let mut arrays = Vec::with_capacity(schema.fields().len());
+    col.arrays(&mut arrays)?;
+    RecordBatch::try_new(schema, arrays)
+}
+
+/// Writes the whole `table` to `writer` as the Arrow IPC file format, one record
+/// batch per generated batch.
+pub fn write_ipc<T, W>(table: T, writer: W) -> Result<(), ArrowError>
+where
+    T: Table,
+    <T::Data as Data>::Col: ColToArrow + Default,
+    W: Write,
+{
+    let schema = schema::<T>();
+    let mut ipc = FileWriter::try_new(writer, &schema)?;
+    let mut col = <T::Data as Data>::Col::default();
+    for idx in 0..table.num_batches() {
+        col.clear();
+        table.gen_batch(idx, &mut col);
+        ipc.write(&record_batch(&col, Arc::clone(&schema))?)?;
+    }
+    ipc.finish()?;
+    Ok(())
+}
+
+/// A [Col] that accumulates its rows straight into a typed Arrow array builder.
+///
+/// Where [ColToArrow] copies an already-generated [Vec]-backed column into Arrow
+/// after the fact, these columns *are* Arrow builders: [Table::gen_batch] appends
+/// directly into them, so a batch can be generated and flushed to Parquet without
+/// an intervening copy. They are append-only — [Col::get] panics — and reused
+/// across batches by flushing, so [Col::clear] finishes and discards the builder.
+pub trait ArrowFinish {
+    /// Appends this builder's Arrow field(s) to `fields`, mirroring [ColToArrow::fields].
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>);
+
+    /// Finishes the builder(s), appending the produced array(s) to `arrays`.
+    ///
+    /// This resets the underlying builder(s), leaving them ready for the next batch.
+    fn finish(&mut self, arrays: &mut Vec<ArrayRef>);
+}
+
+/// A [Data] type with a builder-backed [Col] that generates straight into Arrow.
+pub trait ArrowData: Data {
+    /// The [ArrowFinish] builder column used to generate this data into Arrow.
+    type Builder: Col<Self> + ArrowFinish + Default;
+}
+
+macro_rules! arrow_builder_col {
+    ( $data:ty, $builder:ty, $dt:expr ) => {
+        impl Col<$data> for $builder {
+            fn len(&self) -> usize {
+                ArrayBuilder::len(self)
+            }
+
+            fn reserve(&mut self, _additional: usize) {
+                // Arrow builders grow on demand; there is no exposed reserve.
+            }
+
+            fn get<'a>(&'a self, _idx: usize) -> <$data as Data>::Ref<'a> {
+                panic!("arrow builder columns are append-only and cannot be read back")
+            }
+
+            fn push(&mut self, t: <$data as Data>::Ref<'_>) {
+                self.append_value(t);
+            }
+
+            fn clear(&mut self) {
+                let _ = ArrayBuilder::finish(self);
+            }
+
+            fn good_bytes(&self) -> usize {
+                ArrayBuilder::len(self) * std::mem::size_of::<$data>()
+            }
+        }
+
+        impl ArrowFinish for $builder {
+            fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+                fields.push(next_field(idx, $dt, false));
+            }
+
+            fn finish(&mut self, arrays: &mut Vec<ArrayRef>) {
+                arrays.push(ArrayBuilder::finish(self));
+            }
+        }
+
+        impl ArrowData for $data {
+            type Builder = $builder;
+        }
+    };
+}
+
+arrow_builder_col!(bool, BooleanBuilder, DataType::Boolean);
+arrow_builder_col!(u8, UInt8Builder, DataType::UInt8);
+arrow_builder_col!(u16, UInt16Builder, DataType::UInt16);
+arrow_builder_col!(u32, UInt32Builder, DataType::UInt32);
+arrow_builder_col!(u64, UInt64Builder, DataType::UInt64);
+arrow_builder_col!(i8, Int8Builder, DataType::Int8);
+arrow_builder_col!(i16, Int16Builder, DataType::Int16);
+arrow_builder_col!(i32, Int32Builder, DataType::Int32);
+arrow_builder_col!(i64, Int64Builder, DataType::Int64);
+arrow_builder_col!(f32, Float32Builder, DataType::Float32);
+arrow_builder_col!(f64, Float64Builder, DataType::Float64);
+
+impl Col<String> for StringBuilder {
+    fn len(&self) -> usize {
+        ArrayBuilder::len(self)
+    }
+
+    fn reserve(&mut self, _additional: usize) {
+        // Arrow builders grow on demand; there is no exposed reserve.
+    }
+
+    fn get<'a>(&'a self, _idx: usize) -> <String as Data>::Ref<'a> {
+        panic!("arrow builder columns are append-only and cannot be read back")
+    }
+
+    fn push(&mut self, t: <String as Data>::Ref<'_>) {
+        self.append_value(t);
+    }
+
+    fn clear(&mut self) {
+        let _ = ArrayBuilder::finish(self);
+    }
+
+    fn good_bytes(&self) -> usize {
+        ArrayBuilder::len(self) * std::mem::size_of::<i32>()
+    }
+}
+
+impl ArrowFinish for StringBuilder {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+        fields.push(next_field(idx, DataType::Utf8, false));
+    }
+
+    fn finish(&mut self, arrays: &mut Vec<ArrayRef>) {
+        arrays.push(ArrayBuilder::finish(self));
+    }
+}
+
+impl ArrowData for String {
+    type Builder = StringBuilder;
+}
+
+// The `(Vec<bool>, C)` validity column reuses the existing `Col<Option<T>>` impl
+// from [crate::col]; here it just grafts a null bitmap onto the inner builder's
+// array, exactly as the [ColToArrow] impl does.
+impl<C: ArrowFinish> ArrowFinish for (Vec<bool>, C) {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+        let (_, values) = self;
+        let before = fields.len();
+        values.fields(idx, fields);
+        for field in &mut fields[before..] {
+            *field = field.clone().with_nullable(true);
+        }
+    }
+
+    fn finish(&mut self, arrays: &mut Vec<ArrayRef>) {
+        let (set, values) = self;
+        let before = arrays.len();
+        values.finish(arrays);
+        for array in &mut arrays[before..] {
+            let nulls = NullBuffer::from(std::mem::take(set));
+            let data = array
+                .to_data()
+                .into_builder()
+                .nulls(Some(nulls))
+                .build()
+                .expect("validity length matches values");
+            *array = arrow::array::make_array(data);
+        }
+    }
+}
+
+impl<T: ArrowData> ArrowData for Option<T>
+where
+    for<'a> T::Ref<'a>: Default,
+{
+    type Builder = (Vec<bool>, T::Builder);
+}
+
+macro_rules! arrow_finish_tuple {
+    ( $( $col:ident )+ ) => {
+        #[allow(non_snake_case)]
+        impl<$($col: ArrowFinish),+> ArrowFinish for ($($col),+) {
+            fn fields(&self, idx: &mut usize, fields: &mut Vec<Field>) {
+                let ($($col),+) = self;
+                $(
+                    $col.fields(idx, fields);
+                )+
+            }
+
+            fn finish(&mut self, arrays: &mut Vec<ArrayRef>) {
+                let ($($col),+) = self;
+                $(
+                    $col.finish(arrays);
+                )+
+            }
+        }
+    };
+}
+
+arrow_finish_tuple! { C0 C1 C2 }
+arrow_finish_tuple! { C0 C1 C2 C3 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA }
+arrow_finish_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA CB }
+
+macro_rules! arrow_data_tuple {
+    ( $( $data:ident )+ ) => {
+        impl<$($data: ArrowData),+> ArrowData for ($($data),+)
+        where
+            $(<$data as ArrowData>::Builder: Col<$data>),+
+        {
+            type Builder = ($(<$data as ArrowData>::Builder),+);
+        }
+    };
+}
+
+arrow_data_tuple! { T0 T1 T2 }
+arrow_data_tuple! { T0 T1 T2 T3 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 T7 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 T7 T8 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 TA }
+arrow_data_tuple! { T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 TA TB }
+
+/// The Arrow schema derived from a builder-backed [ArrowData] type.
+pub fn builder_schema<T>() -> SchemaRef
+where
+    T: Table,
+    T::Data: ArrowData,
+{
+    let builder = <T::Data as ArrowData>::Builder::default();
+    let mut idx = 0;
+    let mut fields = Vec::new();
+    builder.fields(&mut idx, &mut fields);
+    Arc::new(Schema::new(fields))
+}
+
+/// Writes the whole `table` to `writer` as Parquet, one row group per batch.
+///
+/// Each batch is generated directly into Arrow array builders and flushed, so no
+/// intermediate [Vec]-backed column is materialized.
+pub fn write_parquet<T, W>(table: T, writer: W) -> Result<(), ParquetError>
+where
+    T: Table,
+    T::Data: ArrowData,
+    W: Write + Send,
+{
+    let schema = builder_schema::<T>();
+    let mut parquet = ArrowWriter::try_new(writer, Arc::clone(&schema), None)?;
+    let mut builder = <T::Data as ArrowData>::Builder::default();
+    for idx in 0..table.num_batches() {
+        table.gen_batch(idx, &mut builder);
+        let mut arrays = Vec::with_capacity(schema.fields().len());
+        builder.finish(&mut arrays);
+        parquet.write(&RecordBatch::try_new(Arc::clone(&schema), arrays)?)?;
+        parquet.flush()?;
+    }
+    parquet.close()?;
+    Ok(())
+}
+
+/// Writes `table` to `<dir>/<name>.parquet`, creating the file.
+///
+/// A convenience over [write_parquet] for the common case of one Parquet file
+/// per [Table] in a directory; see the per-[crate::Set] drivers that call it
+/// once per table.
+pub fn write_parquet_file<T>(table: T, dir: impl AsRef<Path>) -> Result<(), ParquetError>
+where
+    T: Table,
+    T::Data: ArrowData,
+{
+    let path = dir.as_ref().join(format!("{}.parquet", table.name()));
+    let file = File::create(path)?;
+    write_parquet(table, file)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::UInt64Array;
+
+    use crate::kvtd::{Kvtd, KvtdConfig};
+    use crate::{DynTable, Table};
+
+    use super::*;
+
+    /// A minimal table with a nullable column, for exercising the null-bitmap
+    /// grafting neither [Kvtd] nor the TPCC tables happen to isolate on its own.
+    #[derive(Debug, Clone)]
+    struct Nullable;
+
+    impl DynTable for Nullable {
+        fn name(&self) -> &'static str {
+            "nullable"
+        }
+
+        fn num_batches(&self) -> usize {
+            1
+        }
+    }
+
+    impl Table for Nullable {
+        type Data = (u64, Option<u64>, u64);
+
+        fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
+            if idx >= self.num_batches() {
+                return;
+            }
+            batch.push((0, Some(10), 0));
+            batch.push((1, None, 0));
+            batch.push((2, Some(30), 0));
+        }
+    }
+
+    /// [ColToArrow]'s offsets + values layout for `String`/`Vec<u8>` columns is
+    /// exactly the "cumulative end offsets -> Arrow start offsets" conversion in
+    /// [offset_buffer]; an off-by-one there would shift every value but the
+    /// first. Reuses [Kvtd]'s known-good output (see the `serde` module's
+    /// `cols` test) as the source of truth.
+    #[test]
+    fn round_trip_vec_col() {
+        let table = Kvtd::init(KvtdConfig {
+            val_bytes: 4,
+            num_rows: 3,
+            max_rows_per_batch: 3,
+        });
+
+        let schema = schema::<Kvtd>();
+        let mut col = <<Kvtd as Table>::Data as Data>::Col::default();
+        table.gen_batch(0, &mut col);
+        let batch = record_batch(&col, schema).unwrap();
+
+        let keys = batch.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(keys.value(0), "0000000000000000");
+        assert_eq!(keys.value(1), "0000000000000001");
+        assert_eq!(keys.value(2), "0000000000000002");
+
+        let vals = batch.column(1).as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(vals.value(0), &[197, 153, 189, 113]);
+        assert_eq!(vals.value(1), &[138, 50, 122, 226]);
+        assert_eq!(vals.value(2), &[79, 203, 55, 83]);
+    }
+
+    /// [ColToArrow]'s `(Vec<bool>, C)` impl grafts the validity set onto the
+    /// inner array after the fact; get the grafted bitmap's bit order wrong and
+    /// values silently line up with the wrong row.
+    #[test]
+    fn round_trip_vec_col_nullable() {
+        let table = Nullable;
+        let schema = schema::<Nullable>();
+        let mut col = <<Nullable as Table>::Data as Data>::Col::default();
+        table.gen_batch(0, &mut col);
+        let batch = record_batch(&col, schema).unwrap();
+
+        let vals = batch.column(1).as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert!(vals.is_valid(0));
+        assert_eq!(vals.value(0), 10);
+        assert!(vals.is_null(1));
+        assert!(vals.is_valid(2));
+        assert_eq!(vals.value(2), 30);
+    }
+
+    /// The builder-backed [ArrowFinish] path grafts its validity bitmap the same
+    /// way but onto a freshly-finished builder array rather than an
+    /// already-materialized one; cover it separately since the two paths share
+    /// no code.
+    #[test]
+    fn round_trip_builder_col_nullable() {
+        let mut builder = <<<Nullable as Table>::Data as ArrowData>::Builder as Default>::default();
+        Col::push(&mut builder, (0, Some(10), 0));
+        Col::push(&mut builder, (1, None, 0));
+        Col::push(&mut builder, (2, Some(30), 0));
+
+        let mut arrays = Vec::new();
+        builder.finish(&mut arrays);
+
+        let vals = arrays[1].as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert!(vals.is_valid(0));
+        assert_eq!(vals.value(0), 10);
+        assert!(vals.is_null(1));
+        assert!(vals.is_valid(2));
+        assert_eq!(vals.value(2), 30);
+    }
+}
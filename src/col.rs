@@ -17,12 +17,16 @@ pub trait Data: Sized + 'static {
 }
 
 /// A column of data of type `T`.
-//
-// TODO: Some sort of `reserve` method.
 pub trait Col<T: Data> {
     /// Returns the number of elements in the column.
     fn len(&self) -> usize;
 
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// For heap-backed columns this is a hint; for fixed-capacity columns it
+    /// asserts the requested capacity is available.
+    fn reserve(&mut self, additional: usize);
+
     /// Retrieves the value at index.
     ///
     /// # Panics
@@ -122,6 +126,10 @@ impl Col<()> for usize {
         }
     }
 
+    fn reserve(&mut self, _additional: usize) {
+        // A count needs no backing storage.
+    }
+
     fn push(&mut self, _: ()) {
         *self += 1;
     }
@@ -146,6 +154,10 @@ macro_rules! col_primitive {
                 self[idx]
             }
 
+            fn reserve(&mut self, additional: usize) {
+                (*self).reserve(additional);
+            }
+
             fn push(&mut self, t: $data) {
                 self.push(t)
             }
@@ -193,6 +205,12 @@ where
         }
     }
 
+    fn reserve(&mut self, additional: usize) {
+        let (set, values) = self;
+        set.reserve(additional);
+        values.reserve(additional);
+    }
+
     fn push(&mut self, t: <Option<T> as Data>::Ref<'_>) {
         let (set, values) = self;
         match t {
@@ -233,6 +251,11 @@ impl Col<String> for (Vec<usize>, String) {
         &concat[start..end]
     }
 
+    fn reserve(&mut self, additional: usize) {
+        let (lens, _) = self;
+        lens.reserve(additional);
+    }
+
     fn push(&mut self, t: <String as Data>::Ref<'_>) {
         let (lens, concat) = self;
         concat.push_str(t);
@@ -264,6 +287,11 @@ impl Col<Vec<u8>> for (Vec<usize>, Vec<u8>) {
         &concat[start..end]
     }
 
+    fn reserve(&mut self, additional: usize) {
+        let (lens, _) = self;
+        lens.reserve(additional);
+    }
+
     fn push(&mut self, t: <Vec<u8> as Data>::Ref<'_>) {
         let (lens, concat) = self;
         concat.extend_from_slice(t);
@@ -304,6 +332,13 @@ macro_rules! col_tuple {
                 ($($col.get(idx)),+)
             }
 
+            fn reserve(&mut self, additional: usize) {
+                let ($($col),+) = self;
+                $(
+                    $col.reserve(additional);
+                )+
+            }
+
             fn push(&mut self, t: <($($data),*) as Data>::Ref<'_>) {
                 let ($($col),+) = self;
                 let ($($data),+) = t;
@@ -331,6 +366,75 @@ macro_rules! col_tuple {
     };
 }
 
+/// A [Col] with a compile-time capacity `N` that stores its elements inline.
+///
+/// Unlike the [Vec]-backed primitive columns, this never touches the heap:
+/// callers who generate fixed-size batches (e.g. a known `max_rows_per_batch`)
+/// can generate directly into stack or arena memory. Pushing past `N` panics.
+#[derive(Debug)]
+pub struct Fixed<T, const N: usize> {
+    data: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> Default for Fixed<T, N> {
+    fn default() -> Self {
+        Fixed {
+            data: [T::default(); N],
+            len: 0,
+        }
+    }
+}
+
+macro_rules! fixed_primitive {
+    ( $data:ident ) => {
+        impl<const N: usize> Col<$data> for Fixed<$data, N> {
+            fn len(&self) -> usize {
+                self.len
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                assert!(
+                    self.len + additional <= N,
+                    "reserve ({}) should fit capacity ({N})",
+                    self.len + additional
+                );
+            }
+
+            fn get<'a>(&'a self, idx: usize) -> <$data as Data>::Ref<'a> {
+                assert!(idx < self.len, "get index ({idx}) should be < len ({})", self.len);
+                self.data[idx]
+            }
+
+            fn push(&mut self, t: $data) {
+                assert!(self.len < N, "push past fixed capacity ({N})");
+                self.data[self.len] = t;
+                self.len += 1;
+            }
+
+            fn clear(&mut self) {
+                self.len = 0;
+            }
+
+            fn good_bytes(&self) -> usize {
+                self.len * std::mem::size_of::<$data>()
+            }
+        }
+    };
+}
+
+fixed_primitive!(bool);
+fixed_primitive!(u8);
+fixed_primitive!(u16);
+fixed_primitive!(u32);
+fixed_primitive!(u64);
+fixed_primitive!(i8);
+fixed_primitive!(i16);
+fixed_primitive!(i32);
+fixed_primitive!(i64);
+fixed_primitive!(f32);
+fixed_primitive!(f64);
+
 col_tuple! { T0 T1 T2; C0 C1 C2 }
 col_tuple! { T0 T1 T2 T3; C0 C1 C2 C3 }
 col_tuple! { T0 T1 T2 T3 T4; C0 C1 C2 C3 C4 }
@@ -4,7 +4,7 @@
 //!
 //! [Differential Dataflow]: https://crates.io/crates/differential-dataflow
 
-use crate::col::Col;
+use crate::col::{Col, Fixed};
 use crate::{DynTable, Set, Table, TableFnMut};
 
 /// Configuration for [Kvtd].
@@ -53,7 +53,7 @@ impl DynTable for Kvtd {
 impl Table for Kvtd {
     type Data = (String, Vec<u8>, u64, i64);
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         let row_start = idx * self.config.max_rows_per_batch;
         let row_end = std::cmp::min(
             row_start + self.config.max_rows_per_batch,
@@ -63,6 +63,7 @@ impl Table for Kvtd {
         if len == 0 {
             return;
         }
+        batch.reserve(len);
 
         let mut key_buf = String::with_capacity(KEY_BYTES);
         let mut val_buf = Vec::with_capacity(self.config.val_bytes);
@@ -168,4 +169,36 @@ mod tests {
         test_case(17, "0000000000000011");
         test_case(u64::MAX as usize, "ffffffffffffffff");
     }
+
+    #[test]
+    fn gen_batch_into_fixed_col() {
+        // The ts/diff columns are fixed-width, so a caller who knows
+        // max_rows_per_batch up front can generate straight into inline,
+        // heap-free storage for those two columns instead of a Vec.
+        type FixedBatch = (
+            (Vec<usize>, String),
+            (Vec<usize>, Vec<u8>),
+            Fixed<u64, 4>,
+            Fixed<i64, 4>,
+        );
+
+        let table = Kvtd::init(KvtdConfig {
+            val_bytes: 4,
+            num_rows: 4,
+            max_rows_per_batch: 4,
+        });
+        let mut batch = FixedBatch::default();
+        table.gen_batch(0, &mut batch);
+
+        assert_eq!(Col::len(&batch), 4);
+        for idx in 0..4 {
+            let (key, val, ts, diff) = Col::get(&batch, idx);
+            let mut expected_key = String::new();
+            to_hex(&mut expected_key, idx);
+            assert_eq!(key, expected_key);
+            assert_eq!(val.len(), 4);
+            assert_eq!(ts, idx as u64);
+            assert_eq!(diff, 1);
+        }
+    }
 }
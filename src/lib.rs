@@ -4,11 +4,24 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+use std::ops::Range;
+
+use crate::col::{Col, Data};
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod col;
 pub mod kvtd;
 
 #[cfg(feature = "serde")]
 pub mod serde;
 
+#[cfg(feature = "async")]
+pub mod stream;
+
+#[cfg(feature = "rand")]
+pub mod tpcc;
+
 /// A named dataset made up of one or more [Table]s.
 pub trait Set {
     /// Configuration necessary for construct this dataset.
@@ -16,6 +29,52 @@ pub trait Set {
 
     /// Construct an instance of this dataset with the given configuration.
     fn init(config: Self::Config) -> Self;
+
+    /// Invokes `f` once for each [Table] in this dataset.
+    fn tables<F: TableFnMut<()>>(&self, f: &mut F);
+}
+
+/// A callback invoked once per [Table] in a [Set].
+///
+/// This is a trait rather than a closure so that it can be generic over the
+/// (heterogeneous) table types a [Set] is made up of.
+pub trait TableFnMut<R> {
+    /// Invokes this callback with the given table.
+    fn call_mut<T: Table>(&mut self, t: T) -> R;
+}
+
+/// The type-erased portion of a [Table].
+///
+/// Split out from [Table] so that it can be named without also naming the
+/// table's [Table::Data] type.
+pub trait DynTable {
+    /// The name of this table.
+    fn name(&self) -> &'static str;
+
+    /// The number of batches of data in this table.
+    fn num_batches(&self) -> usize;
+
+    /// The contiguous range of batch indices belonging to partition `part` of a
+    /// `parts`-way even split of [DynTable::num_batches].
+    ///
+    /// The ranges are disjoint, cover `0..num_batches()`, and differ in size by
+    /// at most one: the first `num_batches() % parts` partitions get one extra
+    /// batch. Because each batch is a pure function of its index, the union of
+    /// every partition's output is identical regardless of `parts`, so the work
+    /// can be split across threads or machines reproducibly. See [gen_partition].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `part >= parts` or `parts == 0`.
+    fn partition(&self, parts: usize, part: usize) -> Range<usize> {
+        assert!(part < parts, "part ({part}) should be < parts ({parts})");
+        let n = self.num_batches();
+        let base = n / parts;
+        let rem = n % parts;
+        let start = part * base + part.min(rem);
+        let len = base + if part < rem { 1 } else { 0 };
+        start..start + len
+    }
 }
 
 /// A named set of data with a uniform schema.
@@ -24,18 +83,245 @@ pub trait Set {
 /// For each parallelization, each batch can be generated purely as a function
 /// of its index and dataset configuration. For vectorization, a batch is
 /// internally arranged in columns.
-pub trait Table {
-    /// The columnar batch with this table's schema.
+pub trait Table: DynTable + Clone {
+    /// The schema of a row in this table.
+    type Data: Data;
+
+    /// Generates the requested batch's data into `batch`.
+    ///
+    /// The caller is responsible for clearing `batch` between batches; this
+    /// appends to whatever is already present so that callers may reuse
+    /// allocations. If the requested index is out of bounds, nothing is
+    /// appended.
     ///
-    /// TODO: Figure out a way to abstract out batches into a columnar trait.
-    type Batch;
+    /// Each batch is a pure function of its index and the dataset
+    /// configuration, so this takes `&self`: a single table may be shared
+    /// across threads, each generating a disjoint set of batches into its own
+    /// scratch buffer. See [gen_parallel].
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C);
+}
 
-    /// The number of batches of data in this table.
-    fn num_batches(&self) -> usize;
+/// Generates every batch of `table` across a [rayon] thread pool, invoking
+/// `batch_fn` once per completed batch.
+///
+/// Each worker owns its own reusable [Col] scratch buffer, so no allocation is
+/// shared across threads. Batches complete in an unspecified order; `batch_fn`
+/// receives the batch index alongside the data if ordering matters.
+#[cfg(feature = "rayon")]
+pub fn gen_parallel<T, F>(table: &T, batch_fn: F)
+where
+    T: Table + Sync,
+    <T::Data as Data>::Col: Default,
+    F: Fn(usize, &<T::Data as Data>::Col) + Sync,
+{
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-    /// Generates the requested batch's data.
-    ///
-    /// This clears the given batch and reuses allocations when possible. If the
-    /// requested index is out of bounds, an empty batch is generated.
-    fn gen_batch(&self, idx: usize, batch: &mut Self::Batch);
+    (0..table.num_batches()).into_par_iter().for_each_init(
+        <T::Data as Data>::Col::default,
+        |batch, idx| {
+            batch.clear();
+            table.gen_batch(idx, batch);
+            batch_fn(idx, batch);
+        },
+    );
+}
+
+/// Reassembles batches that may complete out of order (e.g. from [gen_parallel])
+/// back into ascending index order.
+///
+/// Batches are buffered as they arrive; once the next expected index shows up
+/// (live or already buffered) it's handed to [OrderedWriter::push]'s `emit`,
+/// along with any run of previously-buffered batches it unblocks. This is what
+/// lets [gen_parallel]'s unspecified completion order still produce stable,
+/// index-ordered CSV/Parquet output. See [gen_parallel_ordered].
+#[derive(Debug)]
+pub struct OrderedWriter<T> {
+    next: usize,
+    pending: std::collections::BTreeMap<usize, T>,
+}
+
+impl<T> Default for OrderedWriter<T> {
+    fn default() -> Self {
+        OrderedWriter {
+            next: 0,
+            pending: Default::default(),
+        }
+    }
+}
+
+impl<T> OrderedWriter<T> {
+    /// Creates an empty writer expecting batches starting at index `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffers `item` at `idx`, then calls `emit` once per batch that's now
+    /// next in line: possibly `item` itself, possibly a run of earlier
+    /// arrivals it unblocks.
+    pub fn push(&mut self, idx: usize, item: T, mut emit: impl FnMut(usize, T)) {
+        self.pending.insert(idx, item);
+        while let Some(item) = self.pending.remove(&self.next) {
+            emit(self.next, item);
+            self.next += 1;
+        }
+    }
+}
+
+/// Like [gen_parallel], but reassembles the batches through an [OrderedWriter]
+/// first, so `batch_fn` is invoked in ascending `idx` order regardless of which
+/// worker finishes which batch first.
+///
+/// This costs one clone of each finished batch (to move it off the worker's
+/// reused scratch buffer and into the writer), so prefer [gen_parallel] unless
+/// the consumer genuinely needs index order (e.g. a single CSV/Parquet writer).
+#[cfg(feature = "rayon")]
+pub fn gen_parallel_ordered<T, F>(table: &T, mut batch_fn: F)
+where
+    T: Table + Sync,
+    <T::Data as Data>::Col: Default + Clone + Send,
+    F: FnMut(usize, <T::Data as Data>::Col),
+{
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel();
+    rayon::scope(|s| {
+        s.spawn(move |_| {
+            (0..table.num_batches()).into_par_iter().for_each_init(
+                || (<T::Data as Data>::Col::default(), tx.clone()),
+                |(batch, tx), idx| {
+                    batch.clear();
+                    table.gen_batch(idx, batch);
+                    let _ = tx.send((idx, batch.clone()));
+                },
+            );
+        });
+
+        let mut writer = OrderedWriter::new();
+        for (idx, batch) in rx {
+            writer.push(idx, batch, &mut batch_fn);
+        }
+    });
+}
+
+/// Generates only the batches `table` assigns to partition `part` of `parts`,
+/// invoking `batch_fn` once per batch in index order.
+///
+/// A single reusable [Col] scratch buffer is threaded through the loop. Run this
+/// with a distinct `part` on each of `parts` threads or machines to produce the
+/// whole table exactly once between them. See [DynTable::partition].
+pub fn gen_partition<T, F>(table: &T, parts: usize, part: usize, mut batch_fn: F)
+where
+    T: Table,
+    F: FnMut(usize, &<T::Data as Data>::Col),
+{
+    let mut batch = <T::Data as Data>::Col::default();
+    for idx in table.partition(parts, part) {
+        batch.clear();
+        table.gen_batch(idx, &mut batch);
+        batch_fn(idx, &batch);
+    }
+}
+
+/// A sink invoked once per generated batch, across the (heterogeneous) tables of
+/// a [Set].
+///
+/// This is the partitioning analogue of [TableFnMut]: it lets [gen_partition_set]
+/// hand each table's batches to one sink without naming their differing
+/// [Table::Data] types.
+pub trait PartitionSink {
+    /// Receives one generated batch of `table` (identified by its index).
+    fn batch<T: Table>(&mut self, table: &T, idx: usize, batch: &<T::Data as Data>::Col);
+}
+
+/// Generates partition `part` of `parts` for every [Table] in `set`, feeding
+/// each batch to `sink`.
+///
+/// Invoke this with a distinct `part` on each worker to cover the whole dataset
+/// once; each worker touches a disjoint, reproducible slice of every table.
+pub fn gen_partition_set<S, K>(set: &S, parts: usize, part: usize, sink: &mut K)
+where
+    S: Set,
+    K: PartitionSink,
+{
+    struct Fan<'a, K> {
+        parts: usize,
+        part: usize,
+        sink: &'a mut K,
+    }
+
+    impl<K: PartitionSink> TableFnMut<()> for Fan<'_, K> {
+        fn call_mut<T: Table>(&mut self, t: T) {
+            gen_partition(&t, self.parts, self.part, |idx, batch| {
+                self.sink.batch(&t, idx, batch)
+            });
+        }
+    }
+
+    let mut fan = Fan { parts, part, sink };
+    set.tables(&mut fan);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Batches(usize);
+
+    impl DynTable for Batches {
+        fn name(&self) -> &'static str {
+            "batches"
+        }
+
+        fn num_batches(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn partition() {
+        for n in 0..20 {
+            for parts in 1..=6 {
+                let table = Batches(n);
+                let ranges: Vec<_> = (0..parts).map(|p| table.partition(parts, p)).collect();
+
+                // The partitions are contiguous and cover exactly `0..n`.
+                assert_eq!(ranges[0].start, 0);
+                assert_eq!(ranges[parts - 1].end, n);
+                for w in ranges.windows(2) {
+                    assert_eq!(w[0].end, w[1].start);
+                }
+
+                // Their sizes differ by at most one.
+                let lens: Vec<_> = ranges.iter().map(|r| r.end - r.start).collect();
+                let (min, max) = (lens.iter().min().unwrap(), lens.iter().max().unwrap());
+                assert!(max - min <= 1, "n={n} parts={parts} lens={lens:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn ordered_writer() {
+        let mut writer = OrderedWriter::new();
+        let mut emitted = Vec::new();
+
+        // Batch 1 arrives before batch 0: nothing emits yet.
+        writer.push(1, "b1", |idx, item| emitted.push((idx, item)));
+        assert_eq!(emitted, Vec::new());
+
+        // Batch 3 arrives next: still blocked on 0 and 2.
+        writer.push(3, "b3", |idx, item| emitted.push((idx, item)));
+        assert_eq!(emitted, Vec::new());
+
+        // Batch 0 unblocks just itself and then batch 1, but not batch 3.
+        writer.push(0, "b0", |idx, item| emitted.push((idx, item)));
+        assert_eq!(emitted, vec![(0, "b0"), (1, "b1")]);
+
+        // Batch 2 unblocks itself and the already-buffered batch 3.
+        writer.push(2, "b2", |idx, item| emitted.push((idx, item)));
+        assert_eq!(
+            emitted,
+            vec![(0, "b0"), (1, "b1"), (2, "b2"), (3, "b3")]
+        );
+    }
 }
@@ -2,7 +2,9 @@
 
 //! [serde::Serializer] implementations for crate types.
 
-use serde::ser::SerializeSeq;
+use std::marker::PhantomData;
+
+use serde::ser::{SerializeMap, SerializeSeq};
 
 use crate::col::{Col, Data};
 use crate::Table;
@@ -21,7 +23,7 @@ where
         S: serde::Serializer,
     {
         let Rows(table) = self;
-        let mut table = (*table).clone();
+        let table = (*table).clone();
         let mut batch = <T::Data as Data>::Col::default();
 
         let mut seq = serializer.serialize_seq(None)?;
@@ -36,6 +38,162 @@ where
     }
 }
 
+/// Column-oriented serialization of a [Table]'s data.
+///
+/// Where [Rows] emits a sequence of per-row tuples, this emits a map of
+/// per-field columns (each itself a sequence of that field's values), keyed
+/// positionally (`"f0"`, `"f1"`, ...) since the [Data] tuples carry no names,
+/// mirroring [crate::arrow::ColToArrow]'s field naming. The whole table is
+/// generated once into its internal columnar buffer, so consumers avoid the
+/// per-row tuple reassembly [Rows] forces and get output that matches the
+/// internal batch layout.
+#[derive(Debug)]
+pub struct Cols<'t, T>(pub &'t T);
+
+impl<T> serde::Serialize for Cols<'_, T>
+where
+    T: Table,
+    <T::Data as Data>::Col: SerializeColumns,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let Cols(table) = self;
+        let table = (*table).clone();
+
+        // Generate every batch into one buffer without clearing, so each field
+        // ends up stored contiguously before we walk it column-by-column.
+        let mut cols = <T::Data as Data>::Col::default();
+        for batch_idx in 0..table.num_batches() {
+            table.gen_batch(batch_idx, &mut cols);
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        let mut idx = 0;
+        cols.serialize_columns(&mut idx, &mut map)?;
+        map.end()
+    }
+}
+
+/// Serializes a single typed [Col] as a sequence of its values.
+struct ColSeq<'a, T, C>(&'a C, PhantomData<T>);
+
+impl<'a, T, C> ColSeq<'a, T, C> {
+    fn new(col: &'a C) -> Self {
+        ColSeq(col, PhantomData)
+    }
+}
+
+impl<T, C> serde::Serialize for ColSeq<'_, T, C>
+where
+    T: Data,
+    C: Col<T>,
+    for<'a> T::Ref<'a>: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let ColSeq(col, _) = self;
+        let mut seq = serializer.serialize_seq(Some(col.len()))?;
+        for idx in 0..col.len() {
+            seq.serialize_element(&col.get(idx))?;
+        }
+        seq.end()
+    }
+}
+
+/// A [Col] that can be serialized as one or more positionally-keyed field
+/// columns.
+///
+/// Leaf columns emit a single `"f{idx}"` map entry; tuple columns recurse and
+/// emit one entry per field, mirroring the [Col] tuple impls in [crate::col].
+pub trait SerializeColumns {
+    /// Appends this column's field entry/entries to `map`, advancing `idx`
+    /// once per contributed field.
+    fn serialize_columns<S: SerializeMap>(
+        &self,
+        idx: &mut usize,
+        map: &mut S,
+    ) -> Result<(), S::Error>;
+}
+
+macro_rules! columns_leaf {
+    ( $data:ty, $col:ty ) => {
+        impl SerializeColumns for $col {
+            fn serialize_columns<S: SerializeMap>(
+                &self,
+                idx: &mut usize,
+                map: &mut S,
+            ) -> Result<(), S::Error> {
+                map.serialize_entry(&format!("f{idx}"), &ColSeq::<$data, $col>::new(self))?;
+                *idx += 1;
+                Ok(())
+            }
+        }
+    };
+}
+
+columns_leaf!((), usize);
+columns_leaf!(bool, Vec<bool>);
+columns_leaf!(u8, Vec<u8>);
+columns_leaf!(u16, Vec<u16>);
+columns_leaf!(u32, Vec<u32>);
+columns_leaf!(u64, Vec<u64>);
+columns_leaf!(i8, Vec<i8>);
+columns_leaf!(i16, Vec<i16>);
+columns_leaf!(i32, Vec<i32>);
+columns_leaf!(i64, Vec<i64>);
+columns_leaf!(f32, Vec<f32>);
+columns_leaf!(f64, Vec<f64>);
+columns_leaf!(String, (Vec<usize>, String));
+columns_leaf!(Vec<u8>, (Vec<usize>, Vec<u8>));
+
+impl<T: Data, C: Col<T>> SerializeColumns for (Vec<bool>, C)
+where
+    for<'a> T::Ref<'a>: Default + serde::Serialize,
+{
+    fn serialize_columns<S: SerializeMap>(
+        &self,
+        idx: &mut usize,
+        map: &mut S,
+    ) -> Result<(), S::Error> {
+        map.serialize_entry(
+            &format!("f{idx}"),
+            &ColSeq::<Option<T>, (Vec<bool>, C)>::new(self),
+        )?;
+        *idx += 1;
+        Ok(())
+    }
+}
+
+macro_rules! columns_tuple {
+    ( $( $col:ident )+ ) => {
+        #[allow(non_snake_case)]
+        impl<$($col: SerializeColumns),+> SerializeColumns for ($($col),+) {
+            fn serialize_columns<S: SerializeMap>(&self, idx: &mut usize, map: &mut S) -> Result<(), S::Error> {
+                let ($($col),+) = self;
+                $(
+                    $col.serialize_columns(idx, map)?;
+                )+
+                Ok(())
+            }
+        }
+    };
+}
+
+columns_tuple! { C0 C1 C2 }
+columns_tuple! { C0 C1 C2 C3 }
+columns_tuple! { C0 C1 C2 C3 C4 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA }
+columns_tuple! { C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 CA CB }
+
 #[cfg(test)]
 mod tests {
     use crate::kvtd::{Kvtd, KvtdConfig};
@@ -59,4 +217,22 @@ mod tests {
 ]";
         assert_eq!(actual, EXPECTED);
     }
+
+    #[test]
+    fn cols() {
+        let table = Kvtd::init(KvtdConfig {
+            val_bytes: 4,
+            num_rows: 3,
+            max_rows_per_batch: 3,
+        });
+
+        let actual = serde_json::to_string(&Cols(&table)).unwrap();
+        const EXPECTED: &str = "{\
+\"f0\":[\"0000000000000000\",\"0000000000000001\",\"0000000000000002\"],\
+\"f1\":[[197,153,189,113],[138,50,122,226],[79,203,55,83]],\
+\"f2\":[0,1,2],\
+\"f3\":[1,1,1]\
+}";
+        assert_eq!(actual, EXPECTED);
+    }
 }
@@ -0,0 +1,142 @@
+// Copyright 2023 Daniel Harrison. All Rights Reserved.
+
+//! Async streaming adapters over [Table] and [Set].
+//!
+//! The synchronous [Table::gen_batch] stays the primitive; the streams here are
+//! thin adapters that clear and refill a column buffer per poll, so a
+//! Tokio-based sink (a network writer, an object-store uploader) can apply
+//! backpressure instead of the generator buffering the whole dataset.
+//! [into_batch_stream] reuses one buffer across polls; the per-table streams
+//! behind [into_set_stream] hand each filled buffer off and start a fresh one.
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::col::Data;
+use crate::{Set, Table, TableFnMut};
+
+/// Streams each batch of `table` lazily, one batch per poll.
+///
+/// A single column buffer is threaded through the stream and reused across
+/// polls; each yielded item is a snapshot of that buffer for the current batch.
+pub fn into_batch_stream<T>(table: T) -> impl Stream<Item = <T::Data as Data>::Col>
+where
+    T: Table,
+    <T::Data as Data>::Col: Clone,
+{
+    let init = (table, 0usize, <T::Data as Data>::Col::default());
+    stream::unfold(init, |(table, idx, mut batch)| async move {
+        if idx >= table.num_batches() {
+            return None;
+        }
+        batch.clear();
+        table.gen_batch(idx, &mut batch);
+        let item = batch.clone();
+        Some((item, (table, idx + 1, batch)))
+    })
+}
+
+/// Like [into_batch_stream] but hands ownership of each batch to the consumer
+/// rather than cloning, for tables whose column buffer is not [Clone].
+fn take_batch_stream<T>(table: T) -> impl Stream<Item = <T::Data as Data>::Col>
+where
+    T: Table,
+{
+    let init = (table, 0usize, <T::Data as Data>::Col::default());
+    stream::unfold(init, |(table, idx, mut batch)| async move {
+        if idx >= table.num_batches() {
+            return None;
+        }
+        batch.clear();
+        table.gen_batch(idx, &mut batch);
+        Some((std::mem::take(&mut batch), (table, idx + 1, batch)))
+    })
+}
+
+/// Maps a single generated batch of some [Table] into a common item type.
+///
+/// This is the stream analogue of [TableFnMut]: it lets [into_set_stream]
+/// unify the heterogeneous per-table batch types into one stream item type.
+pub trait BatchMap<O>: Clone + 'static {
+    /// Maps one batch (identified by its index) into an `O`.
+    fn map<T: Table>(&self, idx: usize, batch: <T::Data as Data>::Col) -> O;
+}
+
+/// Interleaves the batch streams of every [Table] in `set` into one stream.
+///
+/// Each table's batches are mapped through `map` into the shared `O` and the
+/// resulting streams are polled together, so a consumer draining this stream
+/// applies backpressure across the whole dataset at once.
+pub fn into_set_stream<S, O, M>(set: &S, map: M) -> impl Stream<Item = O>
+where
+    S: Set,
+    M: BatchMap<O>,
+    O: 'static,
+{
+    struct Collect<O, M> {
+        map: M,
+        streams: Vec<stream::BoxStream<'static, O>>,
+    }
+
+    impl<O: 'static, M: BatchMap<O>> TableFnMut<()> for Collect<O, M> {
+        fn call_mut<T: Table>(&mut self, t: T) {
+            let map = self.map.clone();
+            let stream = take_batch_stream(t)
+                .enumerate()
+                .map(move |(idx, batch)| map.map::<T>(idx, batch));
+            self.streams.push(stream.boxed());
+        }
+    }
+
+    let mut collect = Collect {
+        map,
+        streams: Vec::new(),
+    };
+    set.tables(&mut collect);
+    stream::select_all(collect.streams)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    use crate::col::{Col, Data};
+    use crate::kvtd::{Kvtd, KvtdConfig};
+
+    use super::*;
+
+    fn table() -> Kvtd {
+        Kvtd::init(KvtdConfig {
+            val_bytes: 4,
+            num_rows: 7,
+            max_rows_per_batch: 3,
+        })
+    }
+
+    fn batches_serial(table: &Kvtd) -> Vec<<<Kvtd as Table>::Data as Data>::Col> {
+        let mut batches = Vec::new();
+        let mut batch = <<Kvtd as Table>::Data as Data>::Col::default();
+        for idx in 0..table.num_batches() {
+            batch.clear();
+            table.gen_batch(idx, &mut batch);
+            batches.push(batch.clone());
+        }
+        batches
+    }
+
+    #[test]
+    fn into_batch_stream_matches_serial() {
+        let table = table();
+        let expected = batches_serial(&table);
+        let actual: Vec<_> = block_on(into_batch_stream(table).collect());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn take_batch_stream_matches_serial() {
+        let table = table();
+        let expected = batches_serial(&table);
+        let actual: Vec<_> = block_on(take_batch_stream(table).collect());
+        assert_eq!(actual, expected);
+    }
+}
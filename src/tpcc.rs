@@ -6,6 +6,11 @@
 //!
 //! An OLTP benchmark.
 
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
 use rand::rngs::SmallRng;
 use rand::seq::SliceRandom;
 use rand::{Rng, SeedableRng};
@@ -20,6 +25,31 @@ pub struct TpccConfig {
     pub warehouses: usize,
     /// The value to use as the data generation time.
     pub now: DateTime,
+    /// The per-run `C` constant used by [nurand] when loading `C_LAST`.
+    ///
+    /// The spec requires this be chosen once per run in `[0, 255]`. See
+    /// 2.1.6.1.
+    pub c_load: usize,
+    /// Restricts generation to this half-open range of warehouse ids; `None`
+    /// generates every warehouse in `0..warehouses`.
+    ///
+    /// Every warehouse-scoped table's primary key is a pure function of its
+    /// batch index, so a batch outside this range is recognized and skipped
+    /// before its RNG ever runs. This is what makes it cheap to shard a load
+    /// across machines by warehouse (e.g. `1_000..2_000`).
+    pub warehouse_range: Option<Range<usize>>,
+    /// An optional predicate over a warehouse id, applied in addition to
+    /// [TpccConfig::warehouse_range].
+    ///
+    /// This is a coarser knob than it might look: every warehouse-scoped table
+    /// shares this one warehouse-level predicate, so it can express "every
+    /// other warehouse" but not a predicate over a row's own columns (e.g.
+    /// "only BC-credit customers") — that would need the row generated first,
+    /// giving up the whole point of skipping its RNG. Like the range, this is
+    /// checked against the warehouse id alone before a batch is generated, so
+    /// it can express an arbitrary deterministic subset of warehouses without
+    /// paying for the ones it excludes.
+    pub warehouse_filter: Option<WarehouseFilter>,
 }
 
 impl TpccConfig {
@@ -28,6 +58,48 @@ impl TpccConfig {
         date: 44_973,
         time: 13 * 60 * 60,
     };
+
+    /// Returns whether a warehouse-scoped batch with the given warehouse id
+    /// should be generated under this config's [TpccConfig::warehouse_range]
+    /// and [TpccConfig::warehouse_filter].
+    fn in_scope(&self, w_id: u64) -> bool {
+        if let Some(range) = &self.warehouse_range {
+            if !range.contains(&(w_id as usize)) {
+                return false;
+            }
+        }
+        if let Some(filter) = &self.warehouse_filter {
+            if !filter.matches(w_id) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A caller-supplied predicate over a warehouse id. See
+/// [TpccConfig::warehouse_filter].
+///
+/// Wrapped in a newtype (rather than a bare `Arc<dyn Fn>` field) so that
+/// [TpccConfig] can still derive a usable [Clone] and [Debug].
+#[derive(Clone)]
+pub struct WarehouseFilter(Arc<dyn Fn(u64) -> bool + Send + Sync>);
+
+impl WarehouseFilter {
+    /// Wraps `f` as a [WarehouseFilter].
+    pub fn new(f: impl Fn(u64) -> bool + Send + Sync + 'static) -> Self {
+        WarehouseFilter(Arc::new(f))
+    }
+
+    fn matches(&self, w_id: u64) -> bool {
+        (self.0)(w_id)
+    }
+}
+
+impl fmt::Debug for WarehouseFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WarehouseFilter(..)")
+    }
 }
 
 /// Transaction Processing Performance Council Benchmark C ([TPCC])
@@ -60,6 +132,31 @@ impl Set for Tpcc {
     }
 }
 
+#[cfg(feature = "arrow")]
+impl Tpcc {
+    /// Writes every table in this dataset to `<dir>/<name>.parquet`.
+    ///
+    /// Each table is streamed straight into Arrow builders and flushed batch by
+    /// batch; see [crate::arrow::write_parquet].
+    pub fn write_parquet(
+        &self,
+        dir: impl AsRef<std::path::Path>,
+    ) -> Result<(), parquet::errors::ParquetError> {
+        let dir = dir.as_ref();
+        let config = self.config.clone();
+        crate::arrow::write_parquet_file(Item::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(Warehouse::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(Stock::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(District::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(Customer::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(History::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(Order::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(OrderLine::init(config.clone()), dir)?;
+        crate::arrow::write_parquet_file(NewOrder::init(config), dir)?;
+        Ok(())
+    }
+}
+
 /// A TPCC DateTime.
 #[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(any(test, feature = "serde"), derive(serde::Serialize))]
@@ -91,19 +188,12 @@ const A_STRING_ALPHABET: &[char] = &[
 
 /// The TPCC ITEM table.
 #[derive(Debug, Clone)]
-pub struct Item {
-    // For allocation reuse
-    i_name: String,
-    i_data: String,
-}
+pub struct Item;
 
 impl Item {
     /// Construct an instance of this table with the given configuration.
     pub fn init(_config: TpccConfig) -> Self {
-        Item {
-            i_name: String::with_capacity(24),
-            i_data: String::with_capacity(50),
-        }
+        Item
     }
 }
 
@@ -120,7 +210,7 @@ impl DynTable for Item {
 impl Table for Item {
     type Data = (u64, u64, String, f64, String);
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
@@ -129,11 +219,13 @@ impl Table for Item {
 
         let i_id = idx as u64;
         let i_im_id = rand_int(&mut rng, 1, 10000) as u64;
-        let i_name = reuse(&mut self.i_name, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 14, 24, x)
-        });
+        let mut i_name = String::with_capacity(24);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 14, 24, &mut i_name);
+        let i_name = i_name.as_str();
         let i_price = rand_int(&mut rng, 100, 10000) as f64 / 100.0;
-        let i_data = reuse(&mut self.i_data, |x| rand_original_string(&mut rng, x));
+        let mut i_data = String::with_capacity(50);
+        rand_original_string(&mut rng, &mut i_data);
+        let i_data = i_data.as_str();
 
         batch.push((i_id, i_im_id, i_name, i_price, i_data));
     }
@@ -143,28 +235,12 @@ impl Table for Item {
 #[derive(Debug, Clone)]
 pub struct Warehouse {
     config: TpccConfig,
-
-    // For allocation reuse
-    w_name: String,
-    w_street_1: String,
-    w_street_2: String,
-    w_city: String,
-    w_state: String,
-    w_zip: String,
 }
 
 impl Warehouse {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        Warehouse {
-            config,
-            w_name: String::with_capacity(10),
-            w_street_1: String::with_capacity(20),
-            w_street_2: String::with_capacity(20),
-            w_city: String::with_capacity(20),
-            w_state: String::with_capacity(2),
-            w_zip: String::with_capacity(9),
-        }
+        Warehouse { config }
     }
 }
 
@@ -191,28 +267,36 @@ impl Table for Warehouse {
         f64,
     );
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
+        let w_id = idx as u64;
+        if !self.config.in_scope(w_id) {
+            return;
+        }
+
         let mut rng = SmallRng::seed_from_u64(idx as u64);
 
-        let w_id = idx as u64;
-        let w_name = reuse(&mut self.w_name, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 6, 10, x)
-        });
-        let w_street_1 = reuse(&mut self.w_street_1, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let w_street_2 = reuse(&mut self.w_street_2, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let w_city = reuse(&mut self.w_city, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let w_state = reuse(&mut self.w_state, |x| rand_state(&mut rng, x));
-        let w_zip = reuse(&mut self.w_zip, |x| rand_zip(&mut rng, x));
+        let mut w_name = String::with_capacity(10);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 6, 10, &mut w_name);
+        let w_name = w_name.as_str();
+        let mut w_street_1 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut w_street_1);
+        let w_street_1 = w_street_1.as_str();
+        let mut w_street_2 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut w_street_2);
+        let w_street_2 = w_street_2.as_str();
+        let mut w_city = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut w_city);
+        let w_city = w_city.as_str();
+        let mut w_state = String::with_capacity(2);
+        rand_state(&mut rng, &mut w_state);
+        let w_state = w_state.as_str();
+        let mut w_zip = String::with_capacity(9);
+        rand_zip(&mut rng, &mut w_zip);
+        let w_zip = w_zip.as_str();
         let w_tax = rand_tax(&mut rng);
         let w_ytd = INITIAL_YTD;
         batch.push((
@@ -225,38 +309,12 @@ impl Table for Warehouse {
 #[derive(Debug, Clone)]
 pub struct Stock {
     config: TpccConfig,
-
-    // For allocation reuse
-    s_dist_01: String,
-    s_dist_02: String,
-    s_dist_03: String,
-    s_dist_04: String,
-    s_dist_05: String,
-    s_dist_06: String,
-    s_dist_07: String,
-    s_dist_08: String,
-    s_dist_09: String,
-    s_dist_10: String,
-    s_data: String,
 }
 
 impl Stock {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        Stock {
-            config,
-            s_dist_01: String::with_capacity(24),
-            s_dist_02: String::with_capacity(24),
-            s_dist_03: String::with_capacity(24),
-            s_dist_04: String::with_capacity(24),
-            s_dist_05: String::with_capacity(24),
-            s_dist_06: String::with_capacity(24),
-            s_dist_07: String::with_capacity(24),
-            s_dist_08: String::with_capacity(24),
-            s_dist_09: String::with_capacity(24),
-            s_dist_10: String::with_capacity(24),
-            s_data: String::with_capacity(50),
-        }
+        Stock { config }
     }
 }
 
@@ -291,52 +349,56 @@ impl Table for Stock {
         String,
     );
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
         let s_id = idx as u64;
         let s_w_id = s_id / NUM_STOCK_PER_WAREHOUSE as u64;
+        if !self.config.in_scope(s_w_id) {
+            return;
+        }
+
+        let mut rng = SmallRng::seed_from_u64(idx as u64);
+
         let s_quantity = rand_int(&mut rng, 10, 100) as u64;
-        let s_dist_01 = reuse(&mut self.s_dist_01, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_02 = reuse(&mut self.s_dist_02, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_03 = reuse(&mut self.s_dist_03, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_04 = reuse(&mut self.s_dist_04, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_05 = reuse(&mut self.s_dist_05, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_06 = reuse(&mut self.s_dist_06, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_07 = reuse(&mut self.s_dist_07, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_08 = reuse(&mut self.s_dist_08, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_09 = reuse(&mut self.s_dist_09, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
-        let s_dist_10 = reuse(&mut self.s_dist_10, |x| {
-            rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-        });
+        let mut s_dist_01 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_01);
+        let s_dist_01 = s_dist_01.as_str();
+        let mut s_dist_02 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_02);
+        let s_dist_02 = s_dist_02.as_str();
+        let mut s_dist_03 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_03);
+        let s_dist_03 = s_dist_03.as_str();
+        let mut s_dist_04 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_04);
+        let s_dist_04 = s_dist_04.as_str();
+        let mut s_dist_05 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_05);
+        let s_dist_05 = s_dist_05.as_str();
+        let mut s_dist_06 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_06);
+        let s_dist_06 = s_dist_06.as_str();
+        let mut s_dist_07 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_07);
+        let s_dist_07 = s_dist_07.as_str();
+        let mut s_dist_08 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_08);
+        let s_dist_08 = s_dist_08.as_str();
+        let mut s_dist_09 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_09);
+        let s_dist_09 = s_dist_09.as_str();
+        let mut s_dist_10 = String::with_capacity(24);
+        rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut s_dist_10);
+        let s_dist_10 = s_dist_10.as_str();
         let s_ytd = 0;
         let s_order_cnt = 0;
         let s_remote_cnt = 0;
-        let s_data = reuse(&mut self.s_data, |x| {
-            rand_original_string(&mut rng, x);
-        });
+        let mut s_data = String::with_capacity(50);
+        rand_original_string(&mut rng, &mut s_data);
+        let s_data = s_data.as_str();
         batch.push((
             s_id,
             s_w_id,
@@ -364,28 +426,12 @@ impl Table for Stock {
 #[derive(Debug, Clone)]
 pub struct District {
     config: TpccConfig,
-
-    // For allocation reuse
-    d_name: String,
-    d_street_1: String,
-    d_street_2: String,
-    d_city: String,
-    d_state: String,
-    d_zip: String,
 }
 
 impl District {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        District {
-            config,
-            d_name: String::with_capacity(10),
-            d_street_1: String::with_capacity(20),
-            d_street_2: String::with_capacity(20),
-            d_city: String::with_capacity(20),
-            d_state: String::with_capacity(2),
-            d_zip: String::with_capacity(9),
-        }
+        District { config }
     }
 }
 
@@ -414,29 +460,37 @@ impl Table for District {
         u64,
     );
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
         let d_id = idx as u64;
         let d_w_id = d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
-        let d_name = reuse(&mut self.d_name, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 6, 10, x)
-        });
-        let d_street_1 = reuse(&mut self.d_street_1, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let d_street_2 = reuse(&mut self.d_street_2, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let d_city = reuse(&mut self.d_city, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let d_state = reuse(&mut self.d_state, |x| rand_state(&mut rng, x));
-        let d_zip = reuse(&mut self.d_zip, |x| rand_zip(&mut rng, x));
+        if !self.config.in_scope(d_w_id) {
+            return;
+        }
+
+        let mut rng = SmallRng::seed_from_u64(idx as u64);
+
+        let mut d_name = String::with_capacity(10);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 6, 10, &mut d_name);
+        let d_name = d_name.as_str();
+        let mut d_street_1 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut d_street_1);
+        let d_street_1 = d_street_1.as_str();
+        let mut d_street_2 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut d_street_2);
+        let d_street_2 = d_street_2.as_str();
+        let mut d_city = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut d_city);
+        let d_city = d_city.as_str();
+        let mut d_state = String::with_capacity(2);
+        rand_state(&mut rng, &mut d_state);
+        let d_state = d_state.as_str();
+        let mut d_zip = String::with_capacity(9);
+        rand_zip(&mut rng, &mut d_zip);
+        let d_zip = d_zip.as_str();
         let d_tax = rand_tax(&mut rng);
         let d_ytd = INITIAL_YTD;
         let d_next_o_id = NUM_ORDERS_PER_DISTRICT as u64 + 1;
@@ -460,32 +514,12 @@ impl Table for District {
 #[derive(Debug, Clone)]
 pub struct Customer {
     config: TpccConfig,
-
-    // For allocation reuse
-    c_first: String,
-    c_street_1: String,
-    c_street_2: String,
-    c_city: String,
-    c_state: String,
-    c_zip: String,
-    c_phone: String,
-    c_data: String,
 }
 
 impl Customer {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        Customer {
-            config,
-            c_first: String::with_capacity(16),
-            c_street_1: String::with_capacity(20),
-            c_street_2: String::with_capacity(20),
-            c_city: String::with_capacity(20),
-            c_state: String::with_capacity(2),
-            c_zip: String::with_capacity(9),
-            c_phone: String::with_capacity(16),
-            c_data: String::with_capacity(500),
-        }
+        Customer { config }
     }
 }
 
@@ -524,35 +558,54 @@ impl Table for Customer {
         String,
     );
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
         let c_id = idx as u64;
         let c_d_id = c_id / NUM_CUSTOMERS_PER_DISTRICT as u64;
         let c_w_id = c_d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
-        let c_last = "TODO";
+        if !self.config.in_scope(c_w_id) {
+            return;
+        }
+
+        let mut rng = SmallRng::seed_from_u64(idx as u64);
+
+        // C_LAST is built from a 3-digit index: the first 1000 customers per
+        // district use the customer number directly, the rest draw it from the
+        // non-uniform NURand distribution. See 4.3.2.3.
+        let cust_num = (c_id % NUM_CUSTOMERS_PER_DISTRICT as u64) as usize;
+        let c_last_idx = if cust_num < 1000 {
+            cust_num
+        } else {
+            nurand(&mut rng, 255, 0, 999, self.config.c_load)
+        };
+        let mut c_last = String::with_capacity(16);
+        rand_c_last(c_last_idx, &mut c_last);
+        let c_last = c_last.as_str();
         let c_middle = "OE";
-        let c_first = reuse(&mut self.c_first, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 8, 16, x)
-        });
-        let c_street_1 = reuse(&mut self.c_street_1, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let c_street_2 = reuse(&mut self.c_street_2, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let c_city = reuse(&mut self.c_city, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, x)
-        });
-        let c_state = reuse(&mut self.c_state, |x| rand_state(&mut rng, x));
-        let c_zip = reuse(&mut self.c_zip, |x| rand_zip(&mut rng, x));
-        let c_phone = reuse(&mut self.c_phone, |x| {
-            rand_string(&mut rng, N_STRING_ALPHABET, 16, x)
-        });
+        let mut c_first = String::with_capacity(16);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 8, 16, &mut c_first);
+        let c_first = c_first.as_str();
+        let mut c_street_1 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut c_street_1);
+        let c_street_1 = c_street_1.as_str();
+        let mut c_street_2 = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut c_street_2);
+        let c_street_2 = c_street_2.as_str();
+        let mut c_city = String::with_capacity(20);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 10, 20, &mut c_city);
+        let c_city = c_city.as_str();
+        let mut c_state = String::with_capacity(2);
+        rand_state(&mut rng, &mut c_state);
+        let c_state = c_state.as_str();
+        let mut c_zip = String::with_capacity(9);
+        rand_zip(&mut rng, &mut c_zip);
+        let c_zip = c_zip.as_str();
+        let mut c_phone = String::with_capacity(16);
+        rand_string(&mut rng, N_STRING_ALPHABET, 16, &mut c_phone);
+        let c_phone = c_phone.as_str();
         let c_since = self.config.now;
         let c_credit = if rng.gen_range(0..10) == 0 {
             "BC"
@@ -565,9 +618,9 @@ impl Table for Customer {
         let c_ytd_payment = 10.0;
         let c_payment_cnt = 1;
         let c_delivery_cnt = 0;
-        let c_data = reuse(&mut self.c_data, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 300, 500, x)
-        });
+        let mut c_data = String::with_capacity(500);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 300, 500, &mut c_data);
+        let c_data = c_data.as_str();
 
         batch.push((
             c_id, c_d_id, c_w_id, c_last, c_middle, c_first, c_street_1, c_street_2, c_city,
@@ -601,18 +654,12 @@ impl Table for Customer {
 #[derive(Debug, Clone)]
 pub struct History {
     config: TpccConfig,
-
-    // For allocation reuse
-    h_data: String,
 }
 
 impl History {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        History {
-            config,
-            h_data: String::with_capacity(24),
-        }
+        History { config }
     }
 }
 
@@ -632,23 +679,27 @@ impl DynTable for History {
 impl Table for History {
     type Data = (u64, u64, u64, u64, u64, DateTime, f64, String);
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
         let h_c_id = idx as u64;
         let h_c_d_id = h_c_id / NUM_CUSTOMERS_PER_DISTRICT as u64;
         let h_c_w_id = h_c_d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
+        if !self.config.in_scope(h_c_w_id) {
+            return;
+        }
+
+        let mut rng = SmallRng::seed_from_u64(idx as u64);
+
         let h_d_id = h_c_d_id;
         let h_w_id = h_c_w_id;
         let h_date = self.config.now;
         let h_amount = 10.00;
-        let h_data = reuse(&mut self.h_data, |x| {
-            rand_string_len(&mut rng, A_STRING_ALPHABET, 12, 24, x)
-        });
+        let mut h_data = String::with_capacity(24);
+        rand_string_len(&mut rng, A_STRING_ALPHABET, 12, 24, &mut h_data);
+        let h_data = h_data.as_str();
         batch.push((
             h_c_id, h_c_d_id, h_c_w_id, h_d_id, h_w_id, h_date, h_amount, h_data,
         ));
@@ -659,18 +710,12 @@ impl Table for History {
 #[derive(Debug, Clone)]
 pub struct Order {
     config: TpccConfig,
-
-    // For allocation reuse
-    o_c_ids: Vec<u64>,
 }
 
 impl Order {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        Order {
-            config,
-            o_c_ids: Vec::new(),
-        }
+        Order { config }
     }
 }
 
@@ -687,30 +732,26 @@ impl DynTable for Order {
 impl Table for Order {
     type Data = (u64, u64, u64, u64, DateTime, Option<u64>, u64, u64);
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
         let o_d_id = idx as u64;
         let o_w_id = o_d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
-        let o_entry_d = self.config.now;
+        if !self.config.in_scope(o_w_id) {
+            return;
+        }
 
-        self.o_c_ids.clear();
-        self.o_c_ids.extend(0..NUM_ORDERS_PER_DISTRICT as u64);
-        self.o_c_ids.shuffle(&mut rng);
+        let o_entry_d = self.config.now;
 
-        for idx in 0..NUM_ORDERS_PER_DISTRICT {
-            let o_id = idx as u64;
-            let o_c_id = self.o_c_ids[idx];
-            let o_carrier_id = if o_id < 2_001 {
-                Some(rand_int(&mut rng, 1, 10) as u64)
-            } else {
-                None
-            };
-            let o_ol_cnt = rand_int(&mut rng, 5, 15) as u64;
+        let mut orders = OrderGen::new(o_d_id);
+        for o_id in 0..NUM_ORDERS_PER_DISTRICT as u64 {
+            let OrderRow {
+                o_c_id,
+                o_carrier_id,
+                o_ol_cnt,
+            } = orders.row(o_id);
             let o_all_local = 1;
 
             batch.push((
@@ -731,18 +772,12 @@ impl Table for Order {
 #[derive(Debug, Clone)]
 pub struct OrderLine {
     config: TpccConfig,
-
-    // For allocation reuse
-    ol_dist_info: String,
 }
 
 impl OrderLine {
     /// Construct an instance of this table with the given configuration.
     pub fn init(config: TpccConfig) -> Self {
-        OrderLine {
-            config,
-            ol_dist_info: String::with_capacity(24),
-        }
+        OrderLine { config }
     }
 }
 
@@ -770,24 +805,50 @@ impl Table for OrderLine {
         String,
     );
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
 
-        let mut rng = SmallRng::seed_from_u64(idx as u64);
-
-        let ol_o_id = idx as u64;
-        let ol_d_id = ol_o_id / NUM_ORDERS_PER_DISTRICT as u64;
+        let (ol_d_id, ol_o_id) = order_coords(idx as u64);
         let ol_w_id = ol_d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
+        if !self.config.in_scope(ol_w_id) {
+            return;
+        }
+
         let ol_supply_w_id = ol_w_id;
         let ol_quantity = 5;
 
-        // TODO: Make this match the order.
-        let o_ol_cnt = rand_int(&mut rng, 5, 15);
-        for idx in 0..o_ol_cnt {
-            let ol_number = idx as u64;
-            let ol_i_id = rand_int(&mut rng, 1, 100_000) as u64;
+        // Re-derive the parent order's line count from the same stream [Order]
+        // used, so the two tables agree on `o_ol_cnt`; the `o_id < 2101`
+        // delivery boundary below uses the same cutoff as [Order]'s carrier
+        // id. [OrderLine] generates one order per batch, so a
+        // naive per-call replay would redo the whole district's shuffle and
+        // row stream for every single order in it (quadratic in the district
+        // size); a thread-local cache keyed on the district instead replays
+        // it once per thread and reuses the result for the rest of that
+        // district's orders.
+        let o_ol_cnt = ORDER_LINE_CNTS.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if cache.as_ref().map(|(d_id, _)| *d_id) != Some(ol_d_id) {
+                let mut orders = OrderGen::new(ol_d_id);
+                let cnts = (0..NUM_ORDERS_PER_DISTRICT as u64)
+                    .map(|o_id| orders.row(o_id).o_ol_cnt)
+                    .collect();
+                *cache = Some((ol_d_id, cnts));
+            }
+            cache.as_ref().unwrap().1[ol_o_id as usize]
+        });
+
+        let mut rng = SmallRng::seed_from_u64(idx as u64);
+        for ol_number in 0..o_ol_cnt {
+            // [Item] is warehouse-independent and zero-indexed over
+            // `0..NUM_ITEMS`, so `ol_i_id` is drawn uniformly from that same
+            // range (not via [nurand]) to guarantee it always joins to a real
+            // item; TPC-C only asks for NURand on id selections where the
+            // skew itself is part of the spec (e.g. `c_last`), not on FKs that
+            // must always resolve. See 2.1.6.
+            let ol_i_id = rand_int(&mut rng, 0, NUM_ITEMS - 1) as u64;
             let ol_delivery_id = if ol_o_id < 2_101 {
                 Some(self.config.now)
             } else {
@@ -798,9 +859,9 @@ impl Table for OrderLine {
             } else {
                 0.0
             };
-            let ol_dist_info = reuse(&mut self.ol_dist_info, |x| {
-                rand_string(&mut rng, A_STRING_ALPHABET, 24, x)
-            });
+            let mut ol_dist_info = String::with_capacity(24);
+            rand_string(&mut rng, A_STRING_ALPHABET, 24, &mut ol_dist_info);
+            let ol_dist_info = ol_dist_info.as_str();
             batch.push((
                 ol_o_id,
                 ol_d_id,
@@ -843,7 +904,7 @@ impl DynTable for NewOrder {
 impl Table for NewOrder {
     type Data = (u64, u64, u64);
 
-    fn gen_batch<C: Col<Self::Data>>(&mut self, idx: usize, batch: &mut C) {
+    fn gen_batch<C: Col<Self::Data>>(&self, idx: usize, batch: &mut C) {
         if idx >= self.num_batches() {
             return;
         }
@@ -852,16 +913,121 @@ impl Table for NewOrder {
         let no_o_id = offset + (idx as u64 % NUM_NEW_ORDERS_PER_DISTRICT as u64);
         let no_d_id = idx as u64 / NUM_NEW_ORDERS_PER_DISTRICT as u64;
         let no_w_id = no_d_id / NUM_DISTRICTS_PER_WAREHOUSE as u64;
+        if !self.config.in_scope(no_w_id) {
+            return;
+        }
 
         batch.push((no_o_id, no_d_id, no_w_id));
     }
 }
 
+thread_local! {
+    /// Per-thread memo of the last district's [OrderRow::o_ol_cnt] values,
+    /// indexed by `o_id`. See its use in [OrderLine::gen_batch].
+    ///
+    /// Thread-local (rather than shared behind a lock) so it costs nothing
+    /// across [crate::gen_parallel] workers: each keeps its own memo and never
+    /// contends with another. A worker that hops between districts just
+    /// recomputes, same as before; one that works a district's orders in a
+    /// row (the common case, serial or parallel) hits the cache for all but
+    /// the first.
+    static ORDER_LINE_CNTS: RefCell<Option<(u64, Vec<u64>)>> = RefCell::new(None);
+}
+
+/// Splits a global ORDER-LINE batch index into the `(district, o_id)` it refers
+/// to.
+///
+/// [Order] generates a whole district per batch, so its own index is already a
+/// district index; [OrderLine] generates one order per batch and uses this to
+/// recover which order within which district it is.
+fn order_coords(idx: u64) -> (u64, u64) {
+    let orders = NUM_ORDERS_PER_DISTRICT as u64;
+    (idx / orders, idx % orders)
+}
+
+/// The per-order values [Order] generates that [OrderLine] must agree with.
+struct OrderRow {
+    o_c_id: u64,
+    o_carrier_id: Option<u64>,
+    o_ol_cnt: u64,
+}
+
+/// Replays a single district's ORDER RNG stream.
+///
+/// [Order] takes its `o_c_id`, `o_carrier_id` and `o_ol_cnt` from this, and
+/// [OrderLine] replays the same stream for its `o_ol_cnt`, so the two tables
+/// can't drift on line counts. [OrderLine] does not read `o_carrier_id` from
+/// here; its delivery null boundary is its own `o_id < 2101` check, the same
+/// cutoff [OrderGen::row] uses for the carrier.
+struct OrderGen {
+    d_id: u64,
+    rng: SmallRng,
+    o_c_ids: Vec<u64>,
+}
+
+impl OrderGen {
+    /// `o_c_id` is a uniform permutation, not a [nurand] draw: 4.3.3.1 requires
+    /// the LOAD to assign each district's customers to orders as a permutation
+    /// of `1..=3000`, one order per customer, not an independently-sampled
+    /// (and possibly repeating, possibly non-uniform) id. The
+    /// `referential_integrity_at_scale` test depends on every `o_c_id` landing
+    /// on a real customer, which a permutation guarantees and an independent
+    /// draw would not.
+    fn new(d_id: u64) -> Self {
+        let mut rng = SmallRng::seed_from_u64(d_id);
+        let mut o_c_ids: Vec<u64> = (0..NUM_ORDERS_PER_DISTRICT as u64).collect();
+        o_c_ids.shuffle(&mut rng);
+        OrderGen { d_id, rng, o_c_ids }
+    }
+
+    /// Generates the next order's row, in ascending `o_id` order.
+    ///
+    /// Callers must advance `o_id` from `0` without gaps so the shared RNG stream
+    /// stays aligned with what [Order] produced.
+    fn row(&mut self, o_id: u64) -> OrderRow {
+        // `o_c_ids` is shuffled within the district alone, so it has to be
+        // offset by the district's own customer range to land on a real,
+        // globally-numbered [Customer] row once `d_id` isn't `0`.
+        let o_c_id = self.d_id * NUM_CUSTOMERS_PER_DISTRICT as u64 + self.o_c_ids[o_id as usize];
+        let o_carrier_id = if o_id < 2_101 {
+            Some(rand_int(&mut self.rng, 1, 10) as u64)
+        } else {
+            None
+        };
+        let o_ol_cnt = rand_int(&mut self.rng, 5, 15) as u64;
+        OrderRow {
+            o_c_id,
+            o_carrier_id,
+            o_ol_cnt,
+        }
+    }
+}
+
 /// Returns a number within [min, max] inclusive. See 2.1.4.
 fn rand_int<R: rand::Rng>(rng: &mut R, min: usize, max: usize) -> usize {
     rng.gen_range(min..=max)
 }
 
+/// The non-uniform random helper `NURand(A, x, y)`. See 2.1.6.
+///
+/// `c` is a run-constant in `[0, A]`; see [TpccConfig::c_load].
+fn nurand<R: rand::Rng>(rng: &mut R, a: usize, x: usize, y: usize, c: usize) -> usize {
+    (((rand_int(rng, 0, a) | rand_int(rng, x, y)) + c) % (y - x + 1)) + x
+}
+
+/// The syllables a `C_LAST` name is assembled from. See 4.3.2.3.
+const C_LAST_SYLLABLES: [&str; 10] = [
+    "BAR", "OUGHT", "ABLE", "PRI", "PRES", "ESE", "ANTI", "CALLY", "ATION", "EING",
+];
+
+/// Appends the `C_LAST` name for a 3-digit `idx` in `0..=999`, concatenating one
+/// syllable per digit. See 4.3.2.3.
+fn rand_c_last(idx: usize, x: &mut String) {
+    x.push_str(C_LAST_SYLLABLES[(idx / 100) % 10]);
+    x.push_str(C_LAST_SYLLABLES[(idx / 10) % 10]);
+    x.push_str(C_LAST_SYLLABLES[idx % 10]);
+}
+
 /// Appends a random US state. Spec just says 2 letters.
 fn rand_state<R: rand::Rng>(rng: &mut R, x: &mut String) {
     rand_string(rng, A_STRING_ALPHABET, 2, x)
@@ -921,15 +1087,6 @@ fn rand_string<R: rand::Rng>(rng: &mut R, alphabet: &[char], len: usize, x: &mut
     }
 }
 
-fn reuse<'a, F>(x: &'a mut String, f: F) -> &'a str
-where
-    F: FnOnce(&mut String),
-{
-    x.clear();
-    f(x);
-    x.as_str()
-}
-
 impl Data for DateTime {
     type Ref<'a> = DateTime;
     type Col = Vec<u64>;
@@ -940,6 +1097,10 @@ impl Col<DateTime> for Vec<u64> {
         self.len()
     }
 
+    fn reserve(&mut self, additional: usize) {
+        (*self).reserve(additional);
+    }
+
     fn get<'a>(&'a self, idx: usize) -> <DateTime as Data>::Ref<'a> {
         let x = self[idx].to_le_bytes();
         let mut date = [0u8; 4];
@@ -975,6 +1136,58 @@ impl From<DateTime> for u64 {
     }
 }
 
+/// Generates [DateTime] columns straight into an Arrow timestamp builder, using
+/// the `From<DateTime> for u64` encoding above (the one [DateTime] serializes
+/// as), not the byte layout of the [`Col<DateTime>`] impl for `Vec<u64>`.
+#[cfg(feature = "arrow")]
+impl Col<DateTime> for arrow::array::TimestampSecondBuilder {
+    fn len(&self) -> usize {
+        arrow::array::ArrayBuilder::len(self)
+    }
+
+    fn reserve(&mut self, _additional: usize) {
+        // Arrow builders grow on demand; there is no exposed reserve.
+    }
+
+    fn get<'a>(&'a self, _idx: usize) -> <DateTime as Data>::Ref<'a> {
+        panic!("arrow builder columns are append-only and cannot be read back")
+    }
+
+    fn push(&mut self, t: <DateTime as Data>::Ref<'_>) {
+        self.append_value(u64::from(t) as i64);
+    }
+
+    fn clear(&mut self) {
+        let _ = arrow::array::ArrayBuilder::finish(self);
+    }
+
+    fn good_bytes(&self) -> usize {
+        arrow::array::ArrayBuilder::len(self) * std::mem::size_of::<u64>()
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl crate::arrow::ArrowFinish for arrow::array::TimestampSecondBuilder {
+    fn fields(&self, idx: &mut usize, fields: &mut Vec<arrow::datatypes::Field>) {
+        let field = arrow::datatypes::Field::new(
+            format!("f{idx}"),
+            arrow::datatypes::DataType::Timestamp(arrow::datatypes::TimeUnit::Second, None),
+            false,
+        );
+        *idx += 1;
+        fields.push(field);
+    }
+
+    fn finish(&mut self, arrays: &mut Vec<arrow::array::ArrayRef>) {
+        arrays.push(arrow::array::ArrayBuilder::finish(self));
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl crate::arrow::ArrowData for DateTime {
+    type Builder = arrow::array::TimestampSecondBuilder;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::col::Data;
@@ -1025,16 +1238,16 @@ mod tests {
 ";
 
     const CUSTOMER_EXPECTED: &str = r"
-0,0,0,TODO,OE,B8C36KCyYo8N,5nosWSKlp1,38cYB0av5swrQz,PWviBQwuAY,Ng,917711111,zjFp8pT1VwvZvqQ3WQTxLfCinHti9W2Gjn6E6FfOyS45mclf2SdR2VE74XhqI7H5qbx3QXhIEF3TCOTqSrkfnpS39JBXj8yvQUR01qjsVQPe9OqETxOFniELGm1QxpZ458gjEwZe4PXray0VaJvLIwJwTsNA2hGK1VwraprwZeyMtcyxC926RhPhp8eJ32UKwNkxgqjPHLeLcWV2xOQ3c26yJyc1gpTKjO7TLspvMulCx1QgX1eWhAQrmFY0NTQf9CHFCGoHXDqprpjNxk4rQFpvHeCFkzL0obtjYpNHuSB9vmOlDG4Cg0CLIGcSLKQOiPEocxwYWsN6bJDv8YiOHKWtkm9eCW6HgMd3EXIlwYnxX28to85WvwrKMptdY4LI5iRYqVVNuJYZooQBbKVXtls
-1,0,0,TODO,OE,clvD7gzsS2KDKl,MEoYRktQ5fu9D5kSdA,fHUOAB2Gl3LwFECk7q,3hiWBlI0bp858oClbg,Om,808011111,ByKKNEkF9bTAvOIXcEcevimYbtLGjFr0u22aYxm0dLcWf0nA3N7omkH1d0TITXmFMu8GAFYj54wXooxJANI8SaUBXUzBI0m08cs5n7971KT9lsCC2xUDDSSNPskaXdd6JpIg6hU9YrhMQikeZo8LpieGmPonpKllzEtEcaRQJSYFswbm09w8tuSuIwzFFW2DvkiZBrRclEGDcqgkJs1Jdd3bpdUVgpblAzJqLzRzV2oiMiAXGxz0oqWjGfcn0qX4DIlVlg6VUxR5fOKYOBEWcKu7k2Hg3AiPFb654qcHKV8zVWqXcArtsd6PlTBMoXr3VP3aoD01kf7jAsPb8THJ5tVQOki3AECrijMADF
-2,0,0,TODO,OE,2vKZhcDjza,LvE6MhKa6m,VdRbBRLrsU,hyPdHhJcoYtp35a,AK,310311111,uChJFZ6RPX7PH4qmt0uC6gLzPPNBvdvZQ9UtSF5PdHHqUxIOEQhhPBIx7dUCpuNNpSQ09RTQJMcnPcyjmQ1Vq77eg5IbjpxKH8V9AJ0cm3aHqojTS2T85nhzK5sMCvNDDj8ensgVAyympZGHyWqCqyeOVZ4KuwyoRh0NsLzAGF6DHdHQRnGuLfklcyZ77ItEtLHRL9AH2vPyHAbZcfbtUpQYyoU3LuqRTEDGC7l1HRE0m2cYgPiNTcL637Ln4BXNc9Zb7hk5WSVV0AYZ3rLB2wCs2t6yNyiLzv1PSWCvecu0gkSt4Ggvjr8otegp9iSQfT16THQmKegXQqaRQbmajHr2yLp662AMgWZmg09Mq6dQjcBZzARaTO
-3,0,0,TODO,OE,ZzjjEQL74tbV,o5HcPFIFmHLBb,euf5ojxhpiMaC,sZJpZXGcUFPwnt,8G,711911111,EIjwaVkPhhGq9zdGEVdMfyXvhdtNHTHB2LwGm5tc5oL1P4laV5ouxlYuSLQdYOrnD7FbL9YL9DB2C2ATmngGTjqa9cOwIaO79WkHGbJX5gnnAPuevywPBc5IM2gyrQL38RTtilVd7LXM716FxUt2e1LCtwSKkyFaMzXbZHzohQfRY871LZzaKAUINJoDpigjEGY0CnywS3H6sX1oaLEq7WAvkVKfEUpu6NGNosjMEWLIiIsCr3eEOHGlZrJ0rKErmrApIwIaBU3MyLrqLDIeysH7iPav5GoABro6ZI9YiurziNaG8EVC5XIZkdEOV7520TebvQQRfZSE4bdI4TbJQcThioq8QW8feZRZxdvZkM0plSZlf2lXoJTiFeDPJG5U5IQ
-4,0,0,TODO,OE,loLlDClBPrg,AaJYjBQU31gg,FTKd1d2kEzFpt,yY1eOnd2DFOVpYDQu,TT,892711111,JnmNMyV1519ppMx22m7u5ypMArRXISVyQUbuGHHsdz7jGYjQ2FbhUzg0jrEkX00PK5I9Pk773jJEg7aCTFngXsyuGIhLDndKfI3ttAZIngi2xvhcNcdiW3qmcCaQItJQ2XUYCagncv3vFX1PfnJ9PnRK3mLsuaaqG3BOM2uUjvr74E3ZtjjtrfiFeSXfW93s1QKpTZIyA07TeqgfYHuAn2CRnSIWI7Dw0eaPtP75lrBSYIMxFFkqg7hsYi1zrN9wRrm0XKn3TKrxIshPRjXy9uU3CPeTcrKNzd1p4hcH5VLjQ2syDizVBk4tY5DljRGlgKFw0jAyZUsJYKAicMWEsENOgnMmygvVaQTSjNnP7k03cbeO3IVGIJPZaOTY4dqdDzrumMtZAf6gAYuLyNhQ15gw9gvc5
-5,0,0,TODO,OE,SvMwUGUVwvlyCH,hVFk4mPC2LNRidV2W62,DCewC6vYLnuLnRw,3Fum2KVdzN4TidDd,aO,603111111,EtxlGbcDWOuV5myu4dx2kCUaWlh3qkRHW2mXuVfL6jQgmhW4smROo6uPftvDEytRL5S6QGgbJe66Z6C1kR4xXVHBvd9moIKgw7EBqe92Owo1G31vZUNJ7c2t1OnXbEtuq9UKqXNieVQBrgbB9PIGmEtbNh4eefHvB91leDIiHKqDoJxxJR7Z5nNA62hi571uAzr2IcTOusVYDMLb141hUe8ZkqFTsmEJ5ky2hBNbaq6jGG60lhlT8lhq5N00IW5TztiE31ET7yUYJmp9SZ9hVc91MozPfDOCeDrCrClqjjmwssfA2eT8MOYroStMoIYJl5D9pZWLNQ0d9bXl09ryfrRlKFvul2o69HQ1a0Yy3ed2QM17d1vGBcBk1hJ0TnCcbGW13hYVGQ4WH0XgrAJNnmwKwWrQzGJ23QbKbUBafFi8HK9
-6,0,0,TODO,OE,8sMvn1jd5VK8jK9N,7umSYnrpB7A,PpmOzeedy9,zlu79UHYLlverm,qU,945411111,RBc4BoB8mFSoSn7M1Emyllrs187hAFnGJRpYm5qDYKHD4be0a1EqT6yUqPivJL00kr6zZ78TiByQbqW1u5RXdOUELmtAPoJnpgdXJZcFUDeANVzUV585nFWgLyzSzqgLmdj4of6kcoUFl5G1QcKaLrD0YPgpXJ89TAJp9iLmrbI0WPmHxoowNoJFNcgMj8NjKBa2mSJio8HDs1LLAcW1EuKUbGPQgXWbYblhgrHRAzM4F0PpAXksKdU5e9eIef5K6JTYdXBvjFDhvVEFTZlzAkGSnSWJlnEl7FP8OZRh7FsTktPKRL6uiUEQm4rzJkkyO4wUJC3JsNTn0gKwMN4eu2EcP98gRsv2rYsvCzIUHubm87TUD1AsjN3lI4Sa9WUzUkjKvO9mBkm9j5pEJu2MftR1fPHBaw28rndLqzWeZlzd5eh8oG2bP5j7CR7n1E7tdlCwZnnZn094gXHd6W
-7,0,0,TODO,OE,zVGglQvwCv,1dFUNrvWhi93z0,Pu96ClKUlXmMLMAEik,5HRAR8wu5Y2ScmR,JV,257211111,pHIYmUfyFM6YVqP3gJSE3v5MeUB4vhBLGryVQxcPDXwzKamOChm3yjhtCN234fLX9r31q8xOXViRiSFWcRtVcqwpwoIhEyoPdedR9U3YIRXVYCEUXKfMg0MPlIZtU99H6kKLiodjagHdMU8iLHpXDxcPcvblgakURqop96sxJv7rHDw2iQbo2s0Y904hmGnIhBl1C7qkGkgkjXh1SaHmEJe0hX7HJcymWwFsxCtkIr2zcqPCrqf98dhru9avVz9v8wUdlQxyt93BzHdyzv25iDT2bT76YY4wCBLvWWIsq8HF35bAVz70PWBWuIIsG7ajlAzLPrrbNyaRLlQdd7SSzgSrHvqnFnfdlgBnifFEy5zCr3dvFc5lqSd3nOHvqtGaqLKFOKGvGglL236VPJeEmROgy9tFqwO1JNh50prY5rt5ykxgiAFnxnf5hzBGfaqvUTNDY9dcaXmgzy70sI
-8,0,0,TODO,OE,ukvYqRPNG6nX,LoLLmNh5mZn,v0q5oCcLLa,ZXet9BSBMrNdLJ6uFQU,c6,805111111,64susL6xxAP8klRTT3AeOpQr55EZZSMN3KBB8UPsqoNhD3l9zEY4jX0CIil74gs07hfz4dy9S9Pj3DtXPas43cU8yEE8hXKFieH5Embcv1pNyWZcivxfCJAo7m3QPtl5X3RkZIFpYNh4wC8etkfO124sUxQwUsZxex23MWkIwToDx7PtfCriliGNDeDjNTtdCQaHVephWcstpCL3EvmVEnEOUyje3wHFPnl45cECWdttPTLrnQ7T6FdPICcaQmIOWimXdjS6lD169foDWD8MeJljuztXtW6cJAU6MYnZAwvtdovGndP97TBrSdeBNV1VydS51B5bWiU8o8GrP1yS0T1hfzqjbOhNHBXgVDc8gvyz8SZZdUwSWh9HdLVpRuotUhVD8NHP6nwPk94ow8kgKmHKiuPm7l48YQSPUPGlH7OMXLkgqnsedLD8DZiLfzRP0EYdmri4Kj68AcNrmHFEslUkPVybHa
-9,0,0,TODO,OE,5drxezJz,9P4gPZsngfJ1x7NnnKv,hS2dt3nYp8r,c3LIoU3Gj1YBj7R,LK,361011111,xav0T6sjnN2Qu9Xpyru4T4GOtX4B0CIbR1LETQvgwJh6aVUvHRyBMknjqWGuarKWjlK1hZLu5TFoSdtXfcoMQDZ22kuqjmCHsNDY2YgOEXMuiI6Jcqulin7Om3rdtgMT4P8kPX3XcMSxgCjY53umYeqifuAfITIqfvdBKArVcJcerAEPsgrHMYKkDNVrpMS0AcNESHHjIMV7XeQ1dFM6WccVJnkRqq5LmYRWQle3V6hj1HrumuZUAWtLTZaXHMmCvobB6YIaPuCHYBkIl4nkVjCz8UZFWk3wwfgORr34pxvX9MxttkjCmgusa55wKTffr11WiqIx35OmIXqjbjk6YN1uxQpDmA41C7opqkXhAZjls2cqG4iJFsQg2mnX4oO5SLxVS8gMmVfTYCfRKyfwG7kREGZ3eAXIDfdqyhVkibKUU3nCul3mVqs8IUhnOqKiWs1fJCKU9iFe2vTCF20Smn
+0,0,0,BARBARBAR,OE,B8C36KCyYo8N,5nosWSKlp1,38cYB0av5swrQz,PWviBQwuAY,Ng,917711111,zjFp8pT1VwvZvqQ3WQTxLfCinHti9W2Gjn6E6FfOyS45mclf2SdR2VE74XhqI7H5qbx3QXhIEF3TCOTqSrkfnpS39JBXj8yvQUR01qjsVQPe9OqETxOFniELGm1QxpZ458gjEwZe4PXray0VaJvLIwJwTsNA2hGK1VwraprwZeyMtcyxC926RhPhp8eJ32UKwNkxgqjPHLeLcWV2xOQ3c26yJyc1gpTKjO7TLspvMulCx1QgX1eWhAQrmFY0NTQf9CHFCGoHXDqprpjNxk4rQFpvHeCFkzL0obtjYpNHuSB9vmOlDG4Cg0CLIGcSLKQOiPEocxwYWsN6bJDv8YiOHKWtkm9eCW6HgMd3EXIlwYnxX28to85WvwrKMptdY4LI5iRYqVVNuJYZooQBbKVXtls
+1,0,0,BARBAROUGHT,OE,clvD7gzsS2KDKl,MEoYRktQ5fu9D5kSdA,fHUOAB2Gl3LwFECk7q,3hiWBlI0bp858oClbg,Om,808011111,ByKKNEkF9bTAvOIXcEcevimYbtLGjFr0u22aYxm0dLcWf0nA3N7omkH1d0TITXmFMu8GAFYj54wXooxJANI8SaUBXUzBI0m08cs5n7971KT9lsCC2xUDDSSNPskaXdd6JpIg6hU9YrhMQikeZo8LpieGmPonpKllzEtEcaRQJSYFswbm09w8tuSuIwzFFW2DvkiZBrRclEGDcqgkJs1Jdd3bpdUVgpblAzJqLzRzV2oiMiAXGxz0oqWjGfcn0qX4DIlVlg6VUxR5fOKYOBEWcKu7k2Hg3AiPFb654qcHKV8zVWqXcArtsd6PlTBMoXr3VP3aoD01kf7jAsPb8THJ5tVQOki3AECrijMADF
+2,0,0,BARBARABLE,OE,2vKZhcDjza,LvE6MhKa6m,VdRbBRLrsU,hyPdHhJcoYtp35a,AK,310311111,uChJFZ6RPX7PH4qmt0uC6gLzPPNBvdvZQ9UtSF5PdHHqUxIOEQhhPBIx7dUCpuNNpSQ09RTQJMcnPcyjmQ1Vq77eg5IbjpxKH8V9AJ0cm3aHqojTS2T85nhzK5sMCvNDDj8ensgVAyympZGHyWqCqyeOVZ4KuwyoRh0NsLzAGF6DHdHQRnGuLfklcyZ77ItEtLHRL9AH2vPyHAbZcfbtUpQYyoU3LuqRTEDGC7l1HRE0m2cYgPiNTcL637Ln4BXNc9Zb7hk5WSVV0AYZ3rLB2wCs2t6yNyiLzv1PSWCvecu0gkSt4Ggvjr8otegp9iSQfT16THQmKegXQqaRQbmajHr2yLp662AMgWZmg09Mq6dQjcBZzARaTO
+3,0,0,BARBARPRI,OE,ZzjjEQL74tbV,o5HcPFIFmHLBb,euf5ojxhpiMaC,sZJpZXGcUFPwnt,8G,711911111,EIjwaVkPhhGq9zdGEVdMfyXvhdtNHTHB2LwGm5tc5oL1P4laV5ouxlYuSLQdYOrnD7FbL9YL9DB2C2ATmngGTjqa9cOwIaO79WkHGbJX5gnnAPuevywPBc5IM2gyrQL38RTtilVd7LXM716FxUt2e1LCtwSKkyFaMzXbZHzohQfRY871LZzaKAUINJoDpigjEGY0CnywS3H6sX1oaLEq7WAvkVKfEUpu6NGNosjMEWLIiIsCr3eEOHGlZrJ0rKErmrApIwIaBU3MyLrqLDIeysH7iPav5GoABro6ZI9YiurziNaG8EVC5XIZkdEOV7520TebvQQRfZSE4bdI4TbJQcThioq8QW8feZRZxdvZkM0plSZlf2lXoJTiFeDPJG5U5IQ
+4,0,0,BARBARPRES,OE,loLlDClBPrg,AaJYjBQU31gg,FTKd1d2kEzFpt,yY1eOnd2DFOVpYDQu,TT,892711111,JnmNMyV1519ppMx22m7u5ypMArRXISVyQUbuGHHsdz7jGYjQ2FbhUzg0jrEkX00PK5I9Pk773jJEg7aCTFngXsyuGIhLDndKfI3ttAZIngi2xvhcNcdiW3qmcCaQItJQ2XUYCagncv3vFX1PfnJ9PnRK3mLsuaaqG3BOM2uUjvr74E3ZtjjtrfiFeSXfW93s1QKpTZIyA07TeqgfYHuAn2CRnSIWI7Dw0eaPtP75lrBSYIMxFFkqg7hsYi1zrN9wRrm0XKn3TKrxIshPRjXy9uU3CPeTcrKNzd1p4hcH5VLjQ2syDizVBk4tY5DljRGlgKFw0jAyZUsJYKAicMWEsENOgnMmygvVaQTSjNnP7k03cbeO3IVGIJPZaOTY4dqdDzrumMtZAf6gAYuLyNhQ15gw9gvc5
+5,0,0,BARBARESE,OE,SvMwUGUVwvlyCH,hVFk4mPC2LNRidV2W62,DCewC6vYLnuLnRw,3Fum2KVdzN4TidDd,aO,603111111,EtxlGbcDWOuV5myu4dx2kCUaWlh3qkRHW2mXuVfL6jQgmhW4smROo6uPftvDEytRL5S6QGgbJe66Z6C1kR4xXVHBvd9moIKgw7EBqe92Owo1G31vZUNJ7c2t1OnXbEtuq9UKqXNieVQBrgbB9PIGmEtbNh4eefHvB91leDIiHKqDoJxxJR7Z5nNA62hi571uAzr2IcTOusVYDMLb141hUe8ZkqFTsmEJ5ky2hBNbaq6jGG60lhlT8lhq5N00IW5TztiE31ET7yUYJmp9SZ9hVc91MozPfDOCeDrCrClqjjmwssfA2eT8MOYroStMoIYJl5D9pZWLNQ0d9bXl09ryfrRlKFvul2o69HQ1a0Yy3ed2QM17d1vGBcBk1hJ0TnCcbGW13hYVGQ4WH0XgrAJNnmwKwWrQzGJ23QbKbUBafFi8HK9
+6,0,0,BARBARANTI,OE,8sMvn1jd5VK8jK9N,7umSYnrpB7A,PpmOzeedy9,zlu79UHYLlverm,qU,945411111,RBc4BoB8mFSoSn7M1Emyllrs187hAFnGJRpYm5qDYKHD4be0a1EqT6yUqPivJL00kr6zZ78TiByQbqW1u5RXdOUELmtAPoJnpgdXJZcFUDeANVzUV585nFWgLyzSzqgLmdj4of6kcoUFl5G1QcKaLrD0YPgpXJ89TAJp9iLmrbI0WPmHxoowNoJFNcgMj8NjKBa2mSJio8HDs1LLAcW1EuKUbGPQgXWbYblhgrHRAzM4F0PpAXksKdU5e9eIef5K6JTYdXBvjFDhvVEFTZlzAkGSnSWJlnEl7FP8OZRh7FsTktPKRL6uiUEQm4rzJkkyO4wUJC3JsNTn0gKwMN4eu2EcP98gRsv2rYsvCzIUHubm87TUD1AsjN3lI4Sa9WUzUkjKvO9mBkm9j5pEJu2MftR1fPHBaw28rndLqzWeZlzd5eh8oG2bP5j7CR7n1E7tdlCwZnnZn094gXHd6W
+7,0,0,BARBARCALLY,OE,zVGglQvwCv,1dFUNrvWhi93z0,Pu96ClKUlXmMLMAEik,5HRAR8wu5Y2ScmR,JV,257211111,pHIYmUfyFM6YVqP3gJSE3v5MeUB4vhBLGryVQxcPDXwzKamOChm3yjhtCN234fLX9r31q8xOXViRiSFWcRtVcqwpwoIhEyoPdedR9U3YIRXVYCEUXKfMg0MPlIZtU99H6kKLiodjagHdMU8iLHpXDxcPcvblgakURqop96sxJv7rHDw2iQbo2s0Y904hmGnIhBl1C7qkGkgkjXh1SaHmEJe0hX7HJcymWwFsxCtkIr2zcqPCrqf98dhru9avVz9v8wUdlQxyt93BzHdyzv25iDT2bT76YY4wCBLvWWIsq8HF35bAVz70PWBWuIIsG7ajlAzLPrrbNyaRLlQdd7SSzgSrHvqnFnfdlgBnifFEy5zCr3dvFc5lqSd3nOHvqtGaqLKFOKGvGglL236VPJeEmROgy9tFqwO1JNh50prY5rt5ykxgiAFnxnf5hzBGfaqvUTNDY9dcaXmgzy70sI
+8,0,0,BARBARATION,OE,ukvYqRPNG6nX,LoLLmNh5mZn,v0q5oCcLLa,ZXet9BSBMrNdLJ6uFQU,c6,805111111,64susL6xxAP8klRTT3AeOpQr55EZZSMN3KBB8UPsqoNhD3l9zEY4jX0CIil74gs07hfz4dy9S9Pj3DtXPas43cU8yEE8hXKFieH5Embcv1pNyWZcivxfCJAo7m3QPtl5X3RkZIFpYNh4wC8etkfO124sUxQwUsZxex23MWkIwToDx7PtfCriliGNDeDjNTtdCQaHVephWcstpCL3EvmVEnEOUyje3wHFPnl45cECWdttPTLrnQ7T6FdPICcaQmIOWimXdjS6lD169foDWD8MeJljuztXtW6cJAU6MYnZAwvtdovGndP97TBrSdeBNV1VydS51B5bWiU8o8GrP1yS0T1hfzqjbOhNHBXgVDc8gvyz8SZZdUwSWh9HdLVpRuotUhVD8NHP6nwPk94ow8kgKmHKiuPm7l48YQSPUPGlH7OMXLkgqnsedLD8DZiLfzRP0EYdmri4Kj68AcNrmHFEslUkPVybHa
+9,0,0,BARBAREING,OE,5drxezJz,9P4gPZsngfJ1x7NnnKv,hS2dt3nYp8r,c3LIoU3Gj1YBj7R,LK,361011111,xav0T6sjnN2Qu9Xpyru4T4GOtX4B0CIbR1LETQvgwJh6aVUvHRyBMknjqWGuarKWjlK1hZLu5TFoSdtXfcoMQDZ22kuqjmCHsNDY2YgOEXMuiI6Jcqulin7Om3rdtgMT4P8kPX3XcMSxgCjY53umYeqifuAfITIqfvdBKArVcJcerAEPsgrHMYKkDNVrpMS0AcNESHHjIMV7XeQ1dFM6WccVJnkRqq5LmYRWQle3V6hj1HrumuZUAWtLTZaXHMmCvobB6YIaPuCHYBkIl4nkVjCz8UZFWk3wwfgORr34pxvX9MxttkjCmgusa55wKTffr11WiqIx35OmIXqjbjk6YN1uxQpDmA41C7opqkXhAZjls2cqG4iJFsQg2mnX4oO5SLxVS8gMmVfTYCfRKyfwG7kREGZ3eAXIDfdqyhVkibKUU3nCul3mVqs8IUhnOqKiWs1fJCKU9iFe2vTCF20Smn
 ";
 
     const HISTORY_EXPECTED: &str = r"
@@ -1064,16 +1277,16 @@ mod tests {
 ";
 
     const ORDER_LINE_EXPECTED: &str = r"
-0,0,0,0,58815,0,193157564249808,5,4563.720543686956,yYo8Nd5nosWSKlp1y38cYB0a
-0,0,0,1,34601,0,193157564249808,5,9324.527574856582,swrQzbPWviBQwuAYNgf5lUli
-0,0,0,2,17637,0,193157564249808,5,9605.969006727235,FLMEchyOd0ZyA1W66M9UHzjF
-0,0,0,3,25017,0,193157564249808,5,9811.476349652017,pT1VwvZvqQ3WQTxLfCinHti9
-0,0,0,4,78055,0,193157564249808,5,8732.291209534731,Gjn6E6FfOyS45mclf2SdR2VE
-0,0,0,5,96622,0,193157564249808,5,9085.943936094973,XhqI7H5qbx3QXhIEF3TCOTqS
-0,0,0,6,28701,0,193157564249808,5,1651.206286390627,fnpS39JBXj8yvQUR01qjsVQP
-0,0,0,7,6833,0,193157564249808,5,9936.605085283258,OqETxOFniELGm1QxpZ458gjE
-0,0,0,8,36372,0,193157564249808,5,8350.31735063976,e4PXray0VaJvLIwJwTsNA2hG
-0,0,0,9,76034,0,193157564249808,5,3684.5330644108262,raprwZeyMtcyxC926RhPhp8e
+0,0,0,0,44732,0,193157564249808,5,4391.403926203145,8C36KCyYo8Nd5nosWSKlp1y3
+0,0,0,1,81774,0,193157564249808,5,4355.014823571079,0av5swrQzbPWviBQwuAYNgf5
+0,0,0,2,14470,0,193157564249808,5,7477.407839540643,lik7FLMEchyOd0ZyA1W66M9U
+0,0,0,3,53575,0,193157564249808,5,4103.75056906027,jFp8pT1VwvZvqQ3WQTxLfCin
+0,0,0,4,30748,0,193157564249808,5,1325.1887337802088,9W2Gjn6E6FfOyS45mclf2SdR
+0,0,0,5,87440,0,193157564249808,5,3705.749209764086,VE74XhqI7H5qbx3QXhIEF3TC
+1,0,0,0,3859,0,193157564249808,5,1818.5230502408529,vD7gzsS2KDKllWMEoYRktQ5f
+1,0,0,1,33602,0,193157564249808,5,9974.450277977989,D5kSdAWfHUOAB2Gl3LwFECk7
+1,0,0,2,26096,0,193157564249808,5,7456.187439697643,3hiWBlI0bp858oClbgOmYa1a
+1,0,0,3,44209,0,193157564249808,5,2092.7012764760098,1AnbvDA9VKRKEk2kWmBUTue9
 ";
 
     const NEW_ORDER_EXPECTED: &str = r"
@@ -1090,7 +1303,7 @@ mod tests {
 ";
 
     #[track_caller]
-    fn test_table<T>(mut table: T, expected: &str)
+    fn test_table<T>(table: T, expected: &str)
     where
         T: Table,
         for<'a> <<T as Table>::Data as Data>::Ref<'a>: serde::Serialize,
@@ -1120,6 +1333,9 @@ mod tests {
         let config = TpccConfig {
             warehouses: 1,
             now: TpccConfig::FEB_18_2023_1_PM,
+            c_load: 0,
+            warehouse_range: None,
+            warehouse_filter: None,
         };
         test_table::<Item>(Item::init(config.clone()), ITEM_EXPECTED.trim_start());
         test_table::<Warehouse>(
@@ -1143,4 +1359,218 @@ mod tests {
         );
         test_table::<NewOrder>(NewOrder::init(config), NEW_ORDER_EXPECTED.trim_start());
     }
+
+    #[test]
+    fn warehouse_scoping() {
+        let full = TpccConfig {
+            warehouses: 4,
+            now: TpccConfig::FEB_18_2023_1_PM,
+            c_load: 0,
+            warehouse_range: None,
+            warehouse_filter: None,
+        };
+
+        // `warehouse_range` generates exactly the warehouses in range, in the
+        // same rows a full run would, nothing more or less.
+        let ranged = TpccConfig {
+            warehouse_range: Some(1..3),
+            ..full.clone()
+        };
+        let w_ids = |config: TpccConfig| -> Vec<u64> {
+            let table = Warehouse::init(config);
+            let mut batch = <<Warehouse as Table>::Data as Data>::Col::default();
+            let mut w_ids = Vec::new();
+            for idx in 0..table.num_batches() {
+                batch.clear();
+                table.gen_batch(idx, &mut batch);
+                for i in 0..batch.len() {
+                    w_ids.push(batch.get(i).0);
+                }
+            }
+            w_ids
+        };
+        assert_eq!(w_ids(ranged), vec![1, 2]);
+
+        // `warehouse_filter` is checked in addition to `warehouse_range`.
+        let filtered = TpccConfig {
+            warehouse_range: Some(0..4),
+            warehouse_filter: Some(WarehouseFilter::new(|w_id| w_id % 2 == 0)),
+            ..full
+        };
+        assert_eq!(w_ids(filtered), vec![0, 2]);
+    }
+
+    #[test]
+    fn c_last() {
+        // Hardcoded names, so a wrong syllable table or digit order can't hide
+        // behind an expected value computed the same way as the implementation.
+        #[track_caller]
+        fn test_case(idx: usize, expected: &str) {
+            let mut c_last = String::new();
+            rand_c_last(idx, &mut c_last);
+            assert_eq!(c_last, expected);
+        }
+        test_case(0, "BARBARBAR");
+        test_case(100, "OUGHTBARBAR");
+        test_case(371, "PRICALLYOUGHT");
+        test_case(512, "ESEOUGHTABLE");
+        test_case(999, "EINGEINGEING");
+
+        // Each digit of `idx` selects its own syllable. See 4.3.2.3.
+        for idx in 0..1000 {
+            let mut c_last = String::new();
+            rand_c_last(idx, &mut c_last);
+            let expected = format!(
+                "{}{}{}",
+                C_LAST_SYLLABLES[(idx / 100) % 10],
+                C_LAST_SYLLABLES[(idx / 10) % 10],
+                C_LAST_SYLLABLES[idx % 10],
+            );
+            assert_eq!(c_last, expected);
+        }
+
+        // NURand always lands back in `[x, y]` regardless of the draw or the
+        // per-run `c` constant. See 2.1.6.
+        let mut rng = SmallRng::seed_from_u64(0);
+        for c in 0..=255 {
+            for _ in 0..100 {
+                let idx = nurand(&mut rng, 255, 0, 999, c);
+                assert!((0..=999).contains(&idx), "{idx} out of [0, 999]");
+            }
+        }
+    }
+
+    #[test]
+    fn referential_integrity_at_scale() {
+        // Four warehouses means multiple districts per warehouse and multiple
+        // warehouses total, which is enough to expose any foreign key that was
+        // only ever derived correctly for warehouse 0, district 0.
+        let config = TpccConfig {
+            warehouses: 4,
+            now: TpccConfig::FEB_18_2023_1_PM,
+            c_load: 0,
+            warehouse_range: None,
+            warehouse_filter: None,
+        };
+
+        let item_ids: std::collections::HashSet<u64> = (0..Item.num_batches() as u64).collect();
+
+        let customer_ids: std::collections::HashSet<u64> = {
+            let table = Customer::init(config.clone());
+            let mut batch = <<Customer as Table>::Data as Data>::Col::default();
+            let mut ids = std::collections::HashSet::new();
+            for idx in 0..table.num_batches() {
+                batch.clear();
+                table.gen_batch(idx, &mut batch);
+                for i in 0..batch.len() {
+                    ids.insert(batch.get(i).0);
+                }
+            }
+            ids
+        };
+
+        let order_line = OrderLine::init(config.clone());
+        let mut batch = <<OrderLine as Table>::Data as Data>::Col::default();
+        for idx in 0..order_line.num_batches() {
+            batch.clear();
+            order_line.gen_batch(idx, &mut batch);
+            for i in 0..batch.len() {
+                let ol_i_id = batch.get(i).4;
+                assert!(item_ids.contains(&ol_i_id), "ol_i_id {ol_i_id} not in item");
+            }
+        }
+
+        let order = Order::init(config);
+        let mut batch = <<Order as Table>::Data as Data>::Col::default();
+        for idx in 0..order.num_batches() {
+            batch.clear();
+            order.gen_batch(idx, &mut batch);
+            for i in 0..batch.len() {
+                let o_c_id = batch.get(i).1;
+                assert!(
+                    customer_ids.contains(&o_c_id),
+                    "o_c_id {o_c_id} not in customer"
+                );
+            }
+        }
+    }
+
+    /// Asserts that [crate::gen_parallel_ordered]'s rayon-threaded path produces
+    /// byte-identical CSV output to the serial [Table::gen_batch] loop, for
+    /// every table in the dataset. Each batch's RNG is seeded solely from its
+    /// index, so thread count should never change the output.
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn gen_parallel_matches_serial() {
+        fn csv_bytes_serial<T>(table: &T) -> Vec<u8>
+        where
+            T: Table,
+            for<'a> <<T as Table>::Data as Data>::Ref<'a>: serde::Serialize,
+        {
+            let mut out = Vec::new();
+            let mut writer = csv::Writer::from_writer(&mut out);
+            let mut batch = <T::Data as Data>::Col::default();
+            for idx in 0..table.num_batches() {
+                batch.clear();
+                table.gen_batch(idx, &mut batch);
+                for i in 0..batch.len() {
+                    writer.serialize(&batch.get(i)).unwrap();
+                }
+            }
+            drop(writer);
+            out
+        }
+
+        fn csv_bytes_parallel<T>(table: &T) -> Vec<u8>
+        where
+            T: Table + Sync,
+            <T::Data as Data>::Col: Clone + Send,
+            for<'a> <<T as Table>::Data as Data>::Ref<'a>: serde::Serialize,
+        {
+            let mut out = Vec::new();
+            let mut writer = csv::Writer::from_writer(&mut out);
+            crate::gen_parallel_ordered(table, |_idx, batch| {
+                for i in 0..batch.len() {
+                    writer.serialize(&batch.get(i)).unwrap();
+                }
+            });
+            drop(writer);
+            out
+        }
+
+        fn assert_matches<T>(table: T)
+        where
+            T: Table + Sync,
+            <T::Data as Data>::Col: Clone + Send,
+            for<'a> <<T as Table>::Data as Data>::Ref<'a>: serde::Serialize,
+        {
+            let serial = csv_bytes_serial(&table);
+            let parallel = csv_bytes_parallel(&table);
+            assert_eq!(
+                serial,
+                parallel,
+                "{} differs between serial and parallel generation",
+                table.name()
+            );
+        }
+
+        // Four warehouses spreads each table across enough batches to actually
+        // land on more than one rayon worker.
+        let config = TpccConfig {
+            warehouses: 4,
+            now: TpccConfig::FEB_18_2023_1_PM,
+            c_load: 0,
+            warehouse_range: None,
+            warehouse_filter: None,
+        };
+        assert_matches(Item::init(config.clone()));
+        assert_matches(Warehouse::init(config.clone()));
+        assert_matches(Stock::init(config.clone()));
+        assert_matches(District::init(config.clone()));
+        assert_matches(Customer::init(config.clone()));
+        assert_matches(History::init(config.clone()));
+        assert_matches(Order::init(config.clone()));
+        assert_matches(OrderLine::init(config.clone()));
+        assert_matches(NewOrder::init(config));
+    }
 }